@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+/// Settings are often kept in a plain text file, one `key = value` per line, so that they can be
+/// changed without compiling the program again. Lines starting with `#` are comments.
+pub fn config() {
+    {
+        let text: &str = "
+            # The range of the game
+            low = 1
+            high=50
+
+            max_attempts   =   6
+            this line has no equal sign
+        ";
+
+        let mut settings: Vec<(String, String)> = parse_config(text).into_iter().collect();
+        // The order of the keys in a HashMap is random, so we sort them before printing
+        settings.sort();
+        println!("{:?}", settings);
+
+        // The values are still strings: turning them into numbers is done by `game_config_from_map`
+        println!("{:?}", game_config_from_map(&parse_config(text)));
+    }
+
+    {
+        // Missing keys take their default value, while values that aren't numbers are errors
+        println!("{:?}", game_config_from_map(&parse_config("high = 10")));
+        println!("{:?}", game_config_from_map(&parse_config("high = ten")));
+    }
+
+    // Writing the config back gives text that `parse_config` can read again
+
+    {
+        let config: GameConfig = GameConfig { low: 5, high: 500, max_attempts: Some(9) };
+        let text: String = game_config_to_string(&config);
+        println!("{text}");
+        println!("{:?}", game_config_from_map(&parse_config(&text)));
+    }
+}
+
+/// Reads the `key = value` lines of `text` into a map. The spaces around keys and values are
+/// trimmed, while blank lines, comments and lines without an `=` are skipped. If a key appears
+/// twice, the last value wins.
+pub fn parse_config(text: &str) -> HashMap<String, String> {
+    let mut settings: HashMap<String, String> = HashMap::new();
+
+    for line in text.lines() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // Only the first `=` splits the line, so a value may contain an `=` as well
+        if let Some((key, value)) = line.split_once('=') {
+            settings.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+
+    settings
+}
+
+/// The settings of a guessing game. Without `max_attempts` the player may guess forever.
+#[derive(Debug, PartialEq)]
+pub struct GameConfig {
+    pub low: u32,
+    pub high: u32,
+    pub max_attempts: Option<u32>,
+}
+
+/// The classic game, from 1 to 100 and with no limit on the attempts
+impl Default for GameConfig {
+    fn default() -> GameConfig {
+        GameConfig { low: 1, high: 100, max_attempts: None }
+    }
+}
+
+/// The errors that may occur while reading a [`GameConfig`]
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    /// The value of the key is not a non-negative whole number (a `u32`)
+    InvalidNumber { key: String, value: String },
+    /// `low` is bigger than `high`, so there is no number to guess
+    EmptyRange { low: u32, high: u32 },
+}
+
+/// Builds a [`GameConfig`] out of the settings read by [`parse_config`]. The keys that are missing
+/// take the value of [`GameConfig::default`], while unknown keys are ignored.
+pub fn game_config_from_map(map: &HashMap<String, String>) -> Result<GameConfig, ConfigError> {
+    // A closure, since it's used for three keys and it needs to see `map`
+    let number = |key: &str| -> Result<Option<u32>, ConfigError> {
+        match map.get(key) {
+            Some(value) => value.parse().map(Some).map_err(|_| ConfigError::InvalidNumber {
+                key: key.to_string(),
+                value: value.clone(),
+            }),
+            None => Ok(None),
+        }
+    };
+
+    let default: GameConfig = GameConfig::default();
+    let config: GameConfig = GameConfig {
+        low: number("low")?.unwrap_or(default.low),
+        high: number("high")?.unwrap_or(default.high),
+        max_attempts: number("max_attempts")?.or(default.max_attempts),
+    };
+
+    if config.low > config.high {
+        return Err(ConfigError::EmptyRange { low: config.low, high: config.high });
+    }
+
+    Ok(config)
+}
+
+/// Writes the config as `key = value` lines, in the same order as the fields of [`GameConfig`].
+/// There is no way to write "no limit" as a number, so `max_attempts` is left out when it's `None`.
+pub fn game_config_to_string(config: &GameConfig) -> String {
+    let mut text: String = format!("low = {}\nhigh = {}\n", config.low, config.high);
+
+    if let Some(max_attempts) = config.max_attempts {
+        text.push_str(&format!("max_attempts = {max_attempts}\n"));
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds the map that `parse_config` should return
+    fn map(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn parse_config_skips_comments_and_blank_lines() {
+        let text: &str = "# The range of the game\n\nlow = 1\n   # indented comment\nhigh = 50\n";
+
+        assert_eq!(parse_config(text), map(&[("low", "1"), ("high", "50")]));
+    }
+
+    #[test]
+    fn parse_config_trims_the_spaces() {
+        assert_eq!(parse_config("   max_attempts   =   6   "), map(&[("max_attempts", "6")]));
+        assert_eq!(parse_config("high=50"), map(&[("high", "50")]));
+    }
+
+    #[test]
+    fn parse_config_skips_malformed_lines() {
+        assert_eq!(parse_config("this line has no equal sign\nlow = 3"), map(&[("low", "3")]));
+    }
+
+    #[test]
+    fn parse_config_splits_on_the_first_equal_sign() {
+        assert_eq!(parse_config("formula = a = b\nkey = 1\nkey = 2"),
+                   map(&[("formula", "a = b"), ("key", "2")]));
+    }
+
+    #[test]
+    fn game_config_from_a_full_map() {
+        let settings = map(&[("low", "5"), ("high", "50"), ("max_attempts", "6")]);
+
+        assert_eq!(game_config_from_map(&settings),
+                   Ok(GameConfig { low: 5, high: 50, max_attempts: Some(6) }));
+    }
+
+    #[test]
+    fn game_config_from_a_partial_map_uses_the_defaults() {
+        assert_eq!(game_config_from_map(&map(&[("high", "10"), ("color", "red")])),
+                   Ok(GameConfig { low: 1, high: 10, max_attempts: None }));
+        assert_eq!(game_config_from_map(&HashMap::new()), Ok(GameConfig::default()));
+    }
+
+    #[test]
+    fn game_config_with_an_invalid_number() {
+        assert_eq!(game_config_from_map(&map(&[("high", "ten")])),
+                   Err(ConfigError::InvalidNumber {
+                       key: String::from("high"),
+                       value: String::from("ten"),
+                   }));
+        assert!(game_config_from_map(&map(&[("max_attempts", "-1")])).is_err());
+    }
+
+    #[test]
+    fn game_config_accepts_zero() {
+        assert_eq!(game_config_from_map(&map(&[("low", "0"), ("max_attempts", "0")])),
+                   Ok(GameConfig { low: 0, high: 100, max_attempts: Some(0) }));
+    }
+
+    #[test]
+    fn game_config_with_an_empty_range() {
+        assert_eq!(game_config_from_map(&map(&[("low", "200")])),
+                   Err(ConfigError::EmptyRange { low: 200, high: 100 }));
+    }
+
+    #[test]
+    fn game_config_to_string_writes_every_key() {
+        let config: GameConfig = GameConfig { low: 5, high: 500, max_attempts: Some(9) };
+
+        assert_eq!(game_config_to_string(&config), "low = 5\nhigh = 500\nmax_attempts = 9\n");
+    }
+
+    #[test]
+    fn game_config_to_string_leaves_out_no_limit() {
+        assert_eq!(game_config_to_string(&GameConfig::default()), "low = 1\nhigh = 100\n");
+    }
+
+    #[test]
+    fn game_config_round_trips_through_text() {
+        for config in [GameConfig { low: 5, high: 500, max_attempts: Some(9) },
+                       GameConfig { low: 0, high: u32::MAX, max_attempts: None }] {
+            let text: String = game_config_to_string(&config);
+
+            assert_eq!(game_config_from_map(&parse_config(&text)), Ok(config));
+        }
+    }
+}
@@ -0,0 +1,492 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::Ordering;
+use crate::time::{format_duration, Clock, Stopwatch, SystemClock};
+
+/// The same game of `main.rs`, but this time the secret number lives inside a struct, and the
+/// guesses don't need to come from the keyboard: this way we can also let a program play it.
+pub struct GuessingGame {
+    low: u32,
+    high: u32,
+    secret: u32,
+}
+
+/// What the game answers to a guess
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GuessFeedback {
+    TooLow,
+    TooHigh,
+    Correct,
+}
+
+impl GuessingGame {
+    /// A game with a random secret number between `low` and `high` (both included). If `low` is
+    /// bigger than `high` the two bounds are swapped.
+    pub fn new(low: u32, high: u32) -> GuessingGame {
+        GuessingGame::from_rng(low, high, &mut rand::thread_rng())
+    }
+
+    /// A game whose secret number depends only on `seed`, so that it can be replayed
+    pub fn with_seed(low: u32, high: u32, seed: u64) -> GuessingGame {
+        GuessingGame::from_rng(low, high, &mut StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(low: u32, high: u32, rng: &mut impl Rng) -> GuessingGame {
+        // `gen_range` panics with an empty range, such as 10..=5
+        let (low, high): (u32, u32) = (low.min(high), low.max(high));
+
+        GuessingGame {
+            low,
+            high,
+            secret: rng.gen_range(low..=high),
+        }
+    }
+
+    /// The lowest and the highest number that the secret may be
+    pub fn range(&self) -> (u32, u32) {
+        (self.low, self.high)
+    }
+
+    /// Compares the guess with the secret number, like the `match` in `game_with_loop`
+    pub fn check(&self, guess: u32) -> GuessFeedback {
+        match guess.cmp(&self.secret) {
+            Ordering::Less => GuessFeedback::TooLow,
+            Ordering::Equal => GuessFeedback::Correct,
+            Ordering::Greater => GuessFeedback::TooHigh,
+        }
+    }
+}
+
+/// The default game is the classic one, with a random number between 1 and 100
+impl Default for GuessingGame {
+    fn default() -> GuessingGame {
+        GuessingGame::new(1, 100)
+    }
+}
+
+/// Something that happened during a game
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    GuessMade { value: u32, outcome: GuessFeedback },
+    GameWon { attempts: u32 },
+}
+
+/// A logger receives every event of the game. Since it's a trait, the game doesn't need to know
+/// whether the events get printed, saved, or anything else.
+pub trait GameLogger {
+    fn log(&mut self, event: &GameEvent);
+}
+
+/// Keeps all the events in a vector, so that they can be looked at after the game
+pub struct VecLogger {
+    pub events: Vec<GameEvent>,
+}
+
+impl VecLogger {
+    pub fn new() -> VecLogger {
+        VecLogger { events: Vec::new() }
+    }
+}
+
+impl Default for VecLogger {
+    fn default() -> VecLogger {
+        VecLogger::new()
+    }
+}
+
+impl GameLogger for VecLogger {
+    fn log(&mut self, event: &GameEvent) {
+        self.events.push(event.clone());
+    }
+}
+
+/// Prints every event on the console, as the games in `main.rs` do
+pub struct StdoutLogger;
+
+impl GameLogger for StdoutLogger {
+    fn log(&mut self, event: &GameEvent) {
+        match event {
+            GameEvent::GuessMade { value, outcome: GuessFeedback::TooLow } => {
+                println!("{value}? Ew, that's small")
+            }
+            GameEvent::GuessMade { value, outcome: GuessFeedback::TooHigh } => {
+                println!("{value}? Oh boy, that's a big number")
+            }
+            GameEvent::GuessMade { value, outcome: GuessFeedback::Correct } => {
+                println!("{value}? YOO! You guessed it!")
+            }
+            GameEvent::GameWon { attempts } => println!("Won in {attempts} attempts"),
+        }
+    }
+}
+
+/// Plays the game with the given guesses, telling the logger about each one. Returns the number of
+/// attempts if the secret number was found, or `None` if the guesses ran out before.
+pub fn play(game: &GuessingGame, guesses: impl IntoIterator<Item = u32>,
+            logger: &mut dyn GameLogger) -> Option<u32> {
+    let mut attempts: u32 = 0;
+
+    for guess in guesses {
+        attempts += 1;
+
+        let outcome: GuessFeedback = game.check(guess);
+        logger.log(&GameEvent::GuessMade { value: guess, outcome });
+
+        if outcome == GuessFeedback::Correct {
+            logger.log(&GameEvent::GameWon { attempts });
+            return Some(attempts);
+        }
+    }
+
+    None
+}
+
+/// A summary of how a player did during a game
+#[derive(Debug, PartialEq)]
+pub struct GuessStats {
+    pub total_guesses: u32,
+    pub too_low: u32,
+    pub too_high: u32,
+}
+
+/// Goes through the events recorded during a game (for instance by a [`VecLogger`]) and counts
+/// the guesses, telling apart the ones that were too low from the ones that were too high
+pub fn guess_stats(events: &[GameEvent]) -> GuessStats {
+    let mut stats: GuessStats = GuessStats { total_guesses: 0, too_low: 0, too_high: 0 };
+
+    for event in events {
+        if let GameEvent::GuessMade { outcome, .. } = event {
+            stats.total_guesses += 1;
+
+            match outcome {
+                GuessFeedback::TooLow => stats.too_low += 1,
+                GuessFeedback::TooHigh => stats.too_high += 1,
+                GuessFeedback::Correct => (),
+            }
+        }
+    }
+
+    stats
+}
+
+/// A player that uses binary search: it always guesses the number in the middle of the range
+/// that's still possible, so that every answer cuts the range in half.
+///
+/// The solver is an [`Iterator`]: each call to `next` gives the next guess, and once the secret
+/// number has been guessed it returns `None`. This way it can be passed directly to [`play`], or
+/// collected into a vector to look at all the guesses.
+pub struct Solver {
+    low: u32,
+    high: u32,
+    secret: u32,
+}
+
+impl Solver {
+    pub fn new(low: u32, high: u32, secret: u32) -> Solver {
+        Solver { low, high, secret }
+    }
+
+    /// A solver for the range of the game. It peeks at the secret number only to know whether
+    /// each guess is too low or too high, exactly what the game would tell it.
+    pub fn for_game(game: &GuessingGame) -> Solver {
+        Solver::new(game.low, game.high, game.secret)
+    }
+}
+
+impl Iterator for Solver {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.low > self.high {
+            return None;
+        }
+
+        // Written like this instead of (low + high) / 2 so that the sum can't overflow
+        let guess: u32 = self.low + (self.high - self.low) / 2;
+
+        match guess.cmp(&self.secret) {
+            Ordering::Less => self.low = guess + 1,
+            Ordering::Greater => self.high = guess - 1,
+            Ordering::Equal => {
+                // Found it: an empty range makes every following call return None
+                self.low = 1;
+                self.high = 0;
+            }
+        }
+
+        Some(guess)
+    }
+}
+
+/// How many yes/no answers are needed, at least, to pick one number out of the range: that's
+/// the number of bits of information the range holds, `ceil(log2(high - low + 1))`.
+///
+/// Since every guess of the [`Solver`] halves the range, this is about how many guesses it needs.
+/// A range with a single number holds no information at all, so it needs 0 bits. Like in
+/// [`GuessingGame`], reversed bounds are swapped.
+pub fn bits_needed(low: u32, high: u32) -> u32 {
+    let (low, high): (u32, u32) = (low.min(high), low.max(high));
+    if low == high {
+        return 0;
+    }
+
+    /* The size may be 2^32 when the range is the whole u32, so we compute it as a u64. The bits
+     * needed to write `size - 1` in binary are exactly the ceiling of log2(size).
+     */
+    let size: u64 = (high - low) as u64 + 1;
+    u64::BITS - (size - 1).leading_zeros()
+}
+
+/// Lets the [`Solver`] play against a seeded game, and returns how many guesses it took. Reversed
+/// bounds are swapped, as both [`GuessingGame`] and [`bits_needed`] do.
+///
+/// The count includes the last, correct guess. Because of that the solver may need one guess
+/// more than [`bits_needed`]: with the range 1..=2 it may guess 1, be told it's too low, and only
+/// then guess 2, even if one bit would have been enough to tell the two numbers apart.
+pub fn solver_vs_game(seed: u64, low: u32, high: u32) -> u32 {
+    let game: GuessingGame = GuessingGame::with_seed(low, high, seed);
+    let mut logger: VecLogger = VecLogger::new();
+
+    // The solver always finds the secret, since it's inside the range of the game
+    play(&game, Solver::for_game(&game), &mut logger).unwrap_or(0)
+}
+
+/// Plays a whole game like [`play`], timing it with a [`Stopwatch`] on the given clock, and returns
+/// a short report of how it went
+pub fn play_session(game: &GuessingGame, guesses: impl IntoIterator<Item = u32>,
+                    logger: &mut dyn GameLogger, clock: &dyn Clock) -> String {
+    let stopwatch: Stopwatch = Stopwatch::start(clock);
+    let result: Option<u32> = play(game, guesses, logger);
+    let time: String = format_duration(stopwatch.elapsed_secs(clock));
+
+    match result {
+        Some(attempts) => format!("Won in {attempts} attempts, in {time}"),
+        None => format!("Gave up after {time}"),
+    }
+}
+
+pub fn game() {
+    {
+        // With a fixed seed the secret number is always the same, so we can write the guesses
+        let game: GuessingGame = GuessingGame::with_seed(1, 10, 21);
+        let guesses: Vec<u32> = (1..=10).collect();
+
+        play(&game, guesses.clone(), &mut StdoutLogger);
+
+        let mut logger: VecLogger = VecLogger::new();
+        play(&game, guesses, &mut logger);
+        println!("The VecLogger recorded {} events, the last one is {:?}", logger.events.len(),
+                 logger.events.last());
+
+        let stats: GuessStats = guess_stats(&logger.events);
+        println!("{:?}", stats);
+    }
+
+    {
+        // Trying every number in the range in order always wins, sooner or later
+        let game: GuessingGame = GuessingGame::new(1, 20);
+        let (low, high) = game.range();
+        println!("I'm thinking of a number between {low} and {high}...");
+
+        let mut logger: VecLogger = VecLogger::default();
+        if let Some(attempts) = play(&game, low..=high, &mut logger) {
+            println!("Guessed the random number in {attempts} attempts");
+        }
+    }
+
+    {
+        // The binary search solver needs way fewer attempts than trying every number
+        let guesses: Vec<u32> = Solver::new(1, 100, 37).collect();
+        println!("The solver guessed {:?}", guesses);
+
+        let game: GuessingGame = GuessingGame::default();
+        play(&game, Solver::for_game(&game), &mut StdoutLogger);
+
+        for (low, high) in [(7, 7), (1, 2), (1, 8), (1, 100)] {
+            println!("A number between {low} and {high} needs {} bits", bits_needed(low, high));
+        }
+
+        let attempts: Vec<u32> = (0..10).map(|seed| solver_vs_game(seed, 1, 100)).collect();
+        println!("Attempts of the solver over ten games: {:?}", attempts);
+    }
+
+    {
+        // A session also tells how long the game took: the solver is quite fast
+        let game: GuessingGame = GuessingGame::default();
+        let mut logger: VecLogger = VecLogger::new();
+        let clock: SystemClock = SystemClock::new();
+
+        println!("{}", play_session(&game, Solver::for_game(&game), &mut logger, &clock));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::MockClock;
+    use std::time::Duration;
+
+    #[test]
+    fn vec_logger_records_a_scripted_session() {
+        // The secret number of this game is 9
+        let game: GuessingGame = GuessingGame::with_seed(1, 10, 21);
+        let mut logger: VecLogger = VecLogger::new();
+
+        assert_eq!(play(&game, [5, 10, 9, 1], &mut logger), Some(3));
+        assert_eq!(logger.events, vec![
+            GameEvent::GuessMade { value: 5, outcome: GuessFeedback::TooLow },
+            GameEvent::GuessMade { value: 10, outcome: GuessFeedback::TooHigh },
+            GameEvent::GuessMade { value: 9, outcome: GuessFeedback::Correct },
+            GameEvent::GameWon { attempts: 3 },
+        ]);
+    }
+
+    #[test]
+    fn play_without_the_right_guess() {
+        let game: GuessingGame = GuessingGame::with_seed(1, 10, 21);
+        let mut logger: VecLogger = VecLogger::new();
+
+        assert_eq!(play(&game, [1, 2], &mut logger), None);
+        assert_eq!(logger.events.len(), 2);
+        assert!(!logger.events.iter().any(|event| matches!(event, GameEvent::GameWon { .. })));
+    }
+
+    #[test]
+    fn guess_stats_of_a_recorded_session() {
+        let game: GuessingGame = GuessingGame::with_seed(1, 10, 21);
+        let mut logger: VecLogger = VecLogger::new();
+        play(&game, [3, 10, 5, 12, 9], &mut logger);
+
+        assert_eq!(guess_stats(&logger.events),
+                   GuessStats { total_guesses: 5, too_low: 2, too_high: 2 });
+    }
+
+    #[test]
+    fn guess_stats_without_events() {
+        assert_eq!(guess_stats(&[]), GuessStats { total_guesses: 0, too_low: 0, too_high: 0 });
+    }
+
+    #[test]
+    fn default_game_is_between_1_and_100() {
+        // The secret is random, but it's always inside the range
+        for _ in 0..100 {
+            let game: GuessingGame = GuessingGame::default();
+
+            assert_eq!(game.range(), (1, 100));
+            assert!((1..=100).contains(&game.secret));
+        }
+    }
+
+    #[test]
+    fn solver_ends_with_the_secret() {
+        let guesses: Vec<u32> = Solver::new(1, 100, 37).collect();
+
+        assert_eq!(guesses, vec![50, 25, 37]);
+        assert_eq!(guesses.last(), Some(&37));
+    }
+
+    #[test]
+    fn solver_at_the_edges_of_the_range() {
+        assert_eq!(Solver::new(0, 10, 0).last(), Some(0));
+        assert_eq!(Solver::new(7, 7, 7).collect::<Vec<u32>>(), vec![7]);
+        assert_eq!(Solver::new(0, u32::MAX, u32::MAX).last(), Some(u32::MAX));
+    }
+
+    #[test]
+    fn solver_stops_after_the_secret() {
+        let mut solver: Solver = Solver::new(1, 3, 2);
+
+        assert_eq!(solver.next(), Some(2));
+        assert_eq!(solver.next(), None);
+        assert_eq!(solver.next(), None);
+    }
+
+    #[test]
+    fn bits_needed_for_some_ranges() {
+        assert_eq!(bits_needed(7, 7), 0);
+        assert_eq!(bits_needed(1, 2), 1);
+        assert_eq!(bits_needed(1, 8), 3);
+        assert_eq!(bits_needed(1, 9), 4);
+        assert_eq!(bits_needed(1, 100), 7);
+    }
+
+    #[test]
+    fn bits_needed_for_the_whole_u32() {
+        assert_eq!(bits_needed(0, u32::MAX), 32);
+        assert_eq!(bits_needed(u32::MAX, 0), 32);
+    }
+
+    #[test]
+    fn solver_vs_game_needs_at_most_one_guess_more_than_the_bits() {
+        for (low, high) in [(7, 7), (1, 2), (1, 8), (1, 100), (0, 1000), (5, 1 << 20), (10, 5)] {
+            for seed in 0..50 {
+                let attempts: u32 = solver_vs_game(seed, low, high);
+
+                assert!(attempts >= 1);
+                assert!(attempts <= bits_needed(low, high) + 1,
+                        "{attempts} attempts for {low}..={high} with seed {seed}");
+            }
+        }
+    }
+
+    #[test]
+    fn bits_needed_with_reversed_bounds() {
+        assert_eq!(bits_needed(10, 5), bits_needed(5, 10));
+        assert_eq!(bits_needed(100, 1), 7);
+    }
+
+    #[test]
+    fn solver_vs_game_may_need_the_extra_guess() {
+        // With 1..=2 the solver guesses 1 first, so when the secret is 2 it needs two guesses
+        let attempts: Vec<u32> = (0..50).map(|seed| solver_vs_game(seed, 1, 2)).collect();
+
+        assert!(attempts.contains(&2));
+        assert!(attempts.iter().all(|&a| a <= 2));
+    }
+
+    /// A player that thinks for 10 seconds before each guess: every time a guess is made, it moves
+    /// the mock clock forward
+    struct SlowPlayer<'a> {
+        clock: &'a MockClock,
+    }
+
+    impl GameLogger for SlowPlayer<'_> {
+        fn log(&mut self, event: &GameEvent) {
+            if let GameEvent::GuessMade { .. } = event {
+                self.clock.advance(Duration::from_secs(10));
+            }
+        }
+    }
+
+    #[test]
+    fn play_session_reports_the_time_of_a_win() {
+        let clock: MockClock = MockClock::new();
+        let game: GuessingGame = GuessingGame::with_seed(1, 10, 21);
+
+        let report: String = play_session(&game, 1..=10, &mut SlowPlayer { clock: &clock }, &clock);
+        assert_eq!(report, "Won in 9 attempts, in 00:01:30");
+    }
+
+    #[test]
+    fn play_session_reports_the_time_of_a_loss() {
+        let clock: MockClock = MockClock::new();
+        let game: GuessingGame = GuessingGame::with_seed(1, 10, 21);
+
+        let mut player: SlowPlayer = SlowPlayer { clock: &clock };
+
+        assert_eq!(play_session(&game, [1, 2, 3], &mut player, &clock), "Gave up after 00:00:30");
+    }
+
+    #[test]
+    fn reversed_bounds_are_swapped() {
+        assert_eq!(GuessingGame::new(10, 5).range(), (5, 10));
+
+        for seed in 0..20 {
+            let game: GuessingGame = GuessingGame::with_seed(10, 5, seed);
+            let same: GuessingGame = GuessingGame::with_seed(5, 10, seed);
+
+            assert_eq!(game.range(), (5, 10));
+            assert!((5..=10).all(|guess| game.check(guess) == same.check(guess)));
+        }
+    }
+}
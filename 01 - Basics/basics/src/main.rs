@@ -2,6 +2,10 @@ use std::io;
 use rand::Rng;
 use std::cmp::Ordering;
 
+mod config;
+mod game;
+mod time;
+
 /// This space here above is the prelude: here you put all the dependencies of the file
 
 /// Here below is the `main` function, which gets executed each time that we run the code.
@@ -10,6 +14,9 @@ use std::cmp::Ordering;
 fn main() {
     game_without_loop();
     game_with_loop();
+    game::game();
+    time::time();
+    config::config();
 }
 
 fn game_without_loop() {
@@ -0,0 +1,188 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// A duration is usually stored as a number of seconds (or milliseconds), which is easy to do math
+/// with but not so easy to read. Before showing it to someone we break it into hours, minutes and
+/// seconds, using integer division and the remainder `%`.
+pub fn time() {
+    {
+        for seconds in [0, 61, 3_725, 400_000] {
+            println!("{seconds} seconds are {}", format_duration(seconds));
+        }
+    }
+
+    /* A stopwatch asks the clock what time it is when it starts, and again whenever we want to
+     * know how much time went by. Counting to a million should take less than a second:
+     */
+
+    {
+        let clock: SystemClock = SystemClock::new();
+        let stopwatch: Stopwatch = Stopwatch::start(&clock);
+
+        let total: u64 = (1..=1_000_000).sum();
+        println!("Summed up to {total} in {}", format_duration(stopwatch.elapsed_secs(&clock)));
+    }
+
+    // With a mock clock the time moves only when we say so, so we always know what to expect
+
+    {
+        let clock: MockClock = MockClock::new();
+        let stopwatch: Stopwatch = Stopwatch::start(&clock);
+
+        clock.advance(Duration::from_secs(90));
+        clock.advance(Duration::from_millis(59_500));
+        println!("The mock clock says {}", format_duration(stopwatch.elapsed_secs(&clock)));
+    }
+}
+
+/// Formats a number of seconds as `HH:MM:SS`. Minutes and seconds always take two digits, while
+/// the hours take as many as needed, so that 100 hours are `100:00:00`.
+pub fn format_duration(total_seconds: u64) -> String {
+    let hours: u64 = total_seconds / 3600;
+    let minutes: u64 = total_seconds % 3600 / 60;
+    let seconds: u64 = total_seconds % 60;
+
+    format!("{hours:02}:{minutes:02}:{seconds:02}")
+}
+
+/// Anything that can tell the time. The time is a [`Duration`] since some starting point chosen by
+/// the clock, since only the difference between two times matters to us.
+///
+/// Code that needs the time takes a `&dyn Clock` instead of calling [`Instant::now`] directly:
+/// this way it can be given a fake clock, whose time doesn't depend on how fast the computer is.
+pub trait Clock {
+    fn now(&self) -> Duration;
+}
+
+/// The real clock, which counts the time since it was created
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock { origin: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// Measures how much time goes by from when it's started. It remembers only the time of the start,
+/// so every measurement must be done with the same clock that started it.
+pub struct Stopwatch {
+    start: Duration,
+}
+
+impl Stopwatch {
+    pub fn start(clock: &dyn Clock) -> Stopwatch {
+        Stopwatch { start: clock.now() }
+    }
+
+    /// The whole seconds gone by since the start
+    pub fn elapsed_secs(&self, clock: &dyn Clock) -> u64 {
+        clock.now().saturating_sub(self.start).as_secs()
+    }
+}
+
+/// A fake clock, whose time starts at 0 and moves forward only when [`MockClock::advance`] is
+/// called.
+///
+/// `advance` takes `&self` and not `&mut self`, so that the clock can be moved forward while
+/// something else is holding a reference to it. That's possible because the time is kept in a
+/// [`Cell`], which allows changing a `Copy` value even through a shared reference.
+pub struct MockClock {
+    time: Cell<Duration>,
+}
+
+impl MockClock {
+    pub fn new() -> MockClock {
+        MockClock { time: Cell::new(Duration::ZERO) }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.time.set(self.time.get() + by);
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> MockClock {
+        MockClock::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        self.time.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_pads_with_zeros() {
+        assert_eq!(format_duration(0), "00:00:00");
+        assert_eq!(format_duration(61), "00:01:01");
+        assert_eq!(format_duration(3_725), "01:02:05");
+    }
+
+    #[test]
+    fn format_duration_with_many_hours() {
+        assert_eq!(format_duration(400_000), "111:06:40");
+        assert_eq!(format_duration(360_000), "100:00:00");
+    }
+
+    #[test]
+    fn mock_clock_moves_only_when_advanced() {
+        let clock: MockClock = MockClock::new();
+        assert_eq!(clock.now(), Duration::ZERO);
+
+        clock.advance(Duration::from_secs(5));
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(clock.now(), Duration::from_millis(5_250));
+    }
+
+    #[test]
+    fn stopwatch_measures_the_advanced_time() {
+        let clock: MockClock = MockClock::new();
+        clock.advance(Duration::from_secs(1_000));
+        let stopwatch: Stopwatch = Stopwatch::start(&clock);
+        assert_eq!(stopwatch.elapsed_secs(&clock), 0);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(stopwatch.elapsed_secs(&clock), 90);
+
+        // Only whole seconds are counted
+        clock.advance(Duration::from_millis(59_500));
+        assert_eq!(stopwatch.elapsed_secs(&clock), 149);
+        assert_eq!(format_duration(stopwatch.elapsed_secs(&clock)), "00:02:29");
+    }
+
+    #[test]
+    fn stopwatch_with_a_clock_behind_its_start() {
+        let late: MockClock = MockClock::new();
+        late.advance(Duration::from_secs(60));
+        let stopwatch: Stopwatch = Stopwatch::start(&late);
+
+        assert_eq!(stopwatch.elapsed_secs(&MockClock::new()), 0);
+    }
+
+    #[test]
+    fn system_clock_never_goes_back() {
+        let clock: SystemClock = SystemClock::new();
+        let before: Duration = clock.now();
+
+        assert!(clock.now() >= before);
+    }
+}
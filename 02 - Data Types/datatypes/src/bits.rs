@@ -0,0 +1,76 @@
+/// Integers are stored in memory as a sequence of bits, and Rust lets us work directly on them with
+/// the **bitwise operators**:
+///  - `&` (AND): the bit is 1 only if both bits are 1;
+///  - `|` (OR): the bit is 1 if at least one of the bits is 1;
+///  - `^` (XOR): the bit is 1 if the two bits are different;
+///  - `<<` and `>>`: shift all the bits to the left or to the right.
+pub(crate) fn bits() {
+    let a_number: u32 = 0b1011_0100;
+
+    println!("{:#b} has {} bits set to 1", a_number, count_ones(a_number));
+    println!("Is 64 a power of two? {} | Is 0? {}", is_power_of_two(64), is_power_of_two(0));
+    println!("Setting the bit 0 of {:#b} gives {:#b}", a_number, set_bit(a_number, 0));
+}
+
+/// Counts the bits set to 1, by looking at the last bit and then shifting the number to the right
+pub(crate) fn count_ones(mut n: u32) -> u32 {
+    let mut count: u32 = 0;
+
+    while n != 0 {
+        count += n & 1;
+        n >>= 1;
+    }
+
+    count
+}
+
+/// A power of two has only one bit set to 1: subtracting 1 turns that bit off and all the bits on
+/// its right on, so `n & (n - 1)` is 0 only for powers of two. 0 is not a power of two.
+pub(crate) fn is_power_of_two(n: u32) -> bool {
+    n != 0 && n & (n - 1) == 0
+}
+
+/// Sets to 1 the bit in position `pos` (where 0 is the rightmost bit). A `u32` has only 32 bits,
+/// so for a position of 32 or more the number is returned as it is.
+pub(crate) fn set_bit(n: u32, pos: u8) -> u32 {
+    if pos >= 32 {
+        return n;
+    }
+
+    n | (1 << pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_ones_counts_the_set_bits() {
+        assert_eq!(count_ones(0), 0);
+        assert_eq!(count_ones(0b1011_0100), 4);
+        assert_eq!(count_ones(u32::MAX), 32);
+    }
+
+    #[test]
+    fn is_power_of_two_checks_a_single_bit() {
+        assert!(!is_power_of_two(0));
+        assert!(is_power_of_two(1));
+        assert!(is_power_of_two(64));
+        assert!(is_power_of_two(1 << 31));
+        assert!(!is_power_of_two(6));
+        assert!(!is_power_of_two(u32::MAX));
+    }
+
+    #[test]
+    fn set_bit_sets_only_that_bit() {
+        assert_eq!(set_bit(0b1011_0100, 0), 0b1011_0101);
+        assert_eq!(set_bit(0b1011_0100, 2), 0b1011_0100);
+        assert_eq!(set_bit(0, 31), 1 << 31);
+    }
+
+    #[test]
+    fn set_bit_out_of_range_is_a_no_op() {
+        assert_eq!(set_bit(5, 32), 5);
+        assert_eq!(set_bit(5, u8::MAX), 5);
+    }
+}
@@ -0,0 +1,82 @@
+/// We saw that there are several integer types, each one with a different width. A value that fits
+/// in an `i32` may not fit in a `u8` (which goes from 0 to 255), so converting with `as` may give
+/// an unexpected result: `300 as u8` is `44`, since the extra bits are just cut away. The
+/// `try_into()` method checks the range instead, and returns an error if the value doesn't fit.
+pub(crate) fn conversions() {
+    println!("300 as u8 = {} | i32_to_u8(300) = {:?} | i32_to_u8(200) = {:?}",
+             300_i32 as u8, i32_to_u8(300), i32_to_u8(200));
+    println!("usize_to_i32(42) = {:?} | usize_to_i32(usize::MAX) = {:?}",
+             usize_to_i32(42), usize_to_i32(usize::MAX));
+
+    /* A `char` is 4 bytes wide because it holds a Unicode code point, which is just a number. Not
+     * every number is a valid char though: the values between 0xD800 and 0xDFFF (the so called
+     * "surrogates") and the ones after 0x10FFFF are not valid Unicode scalar values.
+     */
+
+    println!("'A' = {} | '🦀' = {:#X} | 0x1F980 = {:?} | 0xD800 = {:?}",
+             char_to_codepoint('A'), char_to_codepoint('🦀'), codepoint_to_char(0x1F980),
+             codepoint_to_char(0xD800));
+}
+
+/// Converts an `i32` into a `u8`, returning `None` if it's negative or bigger than 255
+pub(crate) fn i32_to_u8(n: i32) -> Option<u8> {
+    n.try_into().ok()
+}
+
+/// Converts a `usize` into an `i32`, returning `None` if it's bigger than `i32::MAX`
+pub(crate) fn usize_to_i32(n: usize) -> Option<i32> {
+    n.try_into().ok()
+}
+
+/// Returns the Unicode code point of a character
+pub(crate) fn char_to_codepoint(c: char) -> u32 {
+    c as u32
+}
+
+/// Returns the character with the given code point, or `None` if it's not a valid Unicode scalar
+/// value
+pub(crate) fn codepoint_to_char(cp: u32) -> Option<char> {
+    char::from_u32(cp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn i32_to_u8_checks_the_range() {
+        assert_eq!(i32_to_u8(0), Some(0));
+        assert_eq!(i32_to_u8(200), Some(200));
+        assert_eq!(i32_to_u8(255), Some(255));
+        assert_eq!(i32_to_u8(256), None);
+        assert_eq!(i32_to_u8(300), None);
+        assert_eq!(i32_to_u8(-1), None);
+    }
+
+    #[test]
+    fn usize_to_i32_checks_the_range() {
+        assert_eq!(usize_to_i32(42), Some(42));
+        assert_eq!(usize_to_i32(i32::MAX as usize), Some(i32::MAX));
+        assert_eq!(usize_to_i32(i32::MAX as usize + 1), None);
+        assert_eq!(usize_to_i32(usize::MAX), None);
+    }
+
+    #[test]
+    fn char_to_codepoint_of_ascii_and_emoji() {
+        assert_eq!(char_to_codepoint('A'), 65);
+        assert_eq!(char_to_codepoint('🦀'), 0x1F980);
+    }
+
+    #[test]
+    fn codepoint_to_char_of_valid_values() {
+        assert_eq!(codepoint_to_char(65), Some('A'));
+        assert_eq!(codepoint_to_char(0x1F980), Some('🦀'));
+    }
+
+    #[test]
+    fn codepoint_to_char_rejects_invalid_values() {
+        assert_eq!(codepoint_to_char(0xD800), None);
+        assert_eq!(codepoint_to_char(0xDFFF), None);
+        assert_eq!(codepoint_to_char(0x110000), None);
+    }
+}
@@ -1,3 +1,6 @@
+mod bits;
+mod conversions;
+
 const WEIRD_INTEGER: i32 = 46;
 
 fn main() {
@@ -199,6 +202,12 @@ fn main() {
     for item in (1..7).rev() {
         println!("{:?}", item + 4)
     }
+
+    // Integers can also be handled bit by bit: the `bits` module shows how
+    bits::bits();
+
+    // Converting a number from a type to another may fail, if the number is too big
+    conversions::conversions();
 }
 
 /// In Rust we can create new function via the `fn` keyword. The format to respect is the following:
@@ -1,10 +1,13 @@
 mod ownership;
 mod references;
+mod smart_pointers;
 
 use ownership::ownership;
 use references::references;
+use smart_pointers::smart_pointers;
 
 fn main() {
     ownership();
     references();
+    smart_pointers();
 }
\ No newline at end of file
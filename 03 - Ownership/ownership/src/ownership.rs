@@ -58,6 +58,9 @@ pub(crate) fn ownership () {
 
     println!("{:?}", another_string);
 
+    // The String asked the allocator for some bytes on the heap, and we can see how many:
+    println!("It holds {} on the heap", format_bytes(another_string.capacity() as u64));
+
     /* Strings literal are useful because we know always at compile time their size, while this
      * doesn't happen with Strings, since they are mutable. We can't reserve an indefinite space in
      * the memory for a string that "might" change. In order to implement it, we need to reserve
@@ -168,6 +171,10 @@ pub(crate) fn ownership () {
         let string_two: String = string_one.clone();
 
         println!("String one: {:?}\nString two: {:?}", string_one, string_two);
+
+        // demonstrate_move() does the same, and it gives us back the clone to look at
+        let (the_clone, length): (String, usize) = demonstrate_move();
+        println!("The clone is {:?}, {} bytes long", the_clone, length);
     }
 
     /* Now, Rust allows to make a special type of copy for Stack-Only data. For instance, let us
@@ -178,6 +185,8 @@ pub(crate) fn ownership () {
         let integer_two: i32 = integer_one;
 
         println!("Integer one: {:?}\nInteger two: {:?}", integer_one, integer_two);
+
+        println!("demonstrate_copy({}) = {:?}", integer_one, demonstrate_copy(integer_one));
     }
 
     /* Since it's simple to make a copy of an element in the stack (since data occupies the same
@@ -230,6 +239,13 @@ pub(crate) fn ownership () {
 
         println!("{:?}", it_came_back);
 
+        // A function can also give back the string together with some data computed from it:
+
+        let (shouted, chars, bytes): (String, usize, usize) =
+            process_string(String::from("perché"));
+
+        println!("{:?} has {:?} characters, but {:?} bytes", shouted, chars, bytes);
+
         /* It's a bit tedious that every time we have to transfer the ownership of some items. Rust,
          * in order to help with such problem, has a concept called borrowing and references that
          * allow us to access data even without ownership. */
@@ -250,4 +266,110 @@ fn making_copy_and_sum (an_integer: i32, to_sum: i32) {
 fn returning_ownership (a_string: String, an_integer: i32) -> (String, i32) {
     // Functions can return tuples containing different data
     return (a_string, an_integer);
-}
\ No newline at end of file
+}
+
+/// Creates a string, clones it and then moves the original into another variable. After the move
+/// only `moved` is valid, while the clone is a separate copy of the data on the heap, so it can be
+/// returned together with the length of the original.
+pub fn demonstrate_move() -> (String, usize) {
+    let original: String = String::from("Hey there!");
+    let length: usize = original.len();
+    let the_clone: String = original.clone();
+
+    let moved: String = original;
+    // println!("{:?}", original);      // This would not compile: original was moved into moved
+
+    println!("moved = {:?} | the_clone = {:?}", moved, the_clone);
+    (the_clone, length)
+}
+
+/// An `i32` has the Copy trait, so `b` is a copy of `a` and both can still be used afterwards
+pub fn demonstrate_copy(a: i32) -> (i32, i32) {
+    let b: i32 = a;
+
+    (a, b)
+}
+
+/// Takes the ownership of a string and gives it back in uppercase, together with the number of
+/// characters and the number of bytes. The two numbers differ when there are characters outside of
+/// ASCII, since they take more than one byte in UTF-8 (like the `é` of "perché").
+fn process_string(s: String) -> (String, usize, usize) {
+    let chars: usize = s.chars().count();
+    let bytes: usize = s.len();
+
+    (s.to_uppercase(), chars, bytes)
+}
+
+/// Formats an amount of bytes in a human-readable way, such as `1.50 KB`. The units are binary,
+/// so each one is 1024 times the previous one (1 KB = 1024 B, 1 MB = 1024 KB, ...).
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 7] = ["B", "KB", "MB", "GB", "TB", "PB", "EB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value: f64 = bytes as f64;
+    let mut unit: usize = 0;
+    // We compare the value rounded as it will be printed, otherwise 1023.999 KB would be printed
+    // as "1024.00 KB" instead of "1.00 MB"
+    while (value * 100.0).round() / 100.0 >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bytes_below_a_kilobyte() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+    }
+
+    #[test]
+    fn format_bytes_with_binary_units() {
+        assert_eq!(format_bytes(1024), "1.00 KB");
+        assert_eq!(format_bytes(1536), "1.50 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 + 256 * 1024), "5.25 MB");
+        assert_eq!(format_bytes(u64::MAX), "16.00 EB");
+    }
+
+    #[test]
+    fn format_bytes_rounds_before_choosing_the_unit() {
+        assert_eq!(format_bytes(1024 * 1024 - 1), "1.00 MB");
+        assert_eq!(format_bytes(1024 * 1024 * 1024 - 1), "1.00 GB");
+        assert_eq!(format_bytes(1024 * 1024 - 6), "1023.99 KB");
+    }
+
+    #[test]
+    fn process_string_of_ascii_text() {
+        assert_eq!(process_string(String::from("hello")), (String::from("HELLO"), 5, 5));
+    }
+
+    #[test]
+    fn process_string_of_multi_byte_text() {
+        let (upper, chars, bytes): (String, usize, usize) = process_string(String::from("perché"));
+
+        assert_eq!(upper, "PERCHÉ");
+        assert_eq!((chars, bytes), (6, 7));
+    }
+
+    #[test]
+    fn demonstrate_move_returns_the_clone_and_the_length() {
+        let (the_clone, length): (String, usize) = demonstrate_move();
+
+        assert_eq!(the_clone, "Hey there!");
+        assert_eq!(length, the_clone.len());
+    }
+
+    #[test]
+    fn demonstrate_copy_keeps_both_values() {
+        assert_eq!(demonstrate_copy(6), (6, 6));
+        assert_eq!(demonstrate_copy(i32::MIN), (i32::MIN, i32::MIN));
+    }
+}
@@ -228,4 +228,159 @@ pub(crate) fn references () {
         println!("{:?} | {:?}", &my_array[1..3], a_slice);
         assert_eq!(&my_array[1..3], a_slice);
     }
-}
\ No newline at end of file
+
+    /* Compare now analyze_string() with process_string() in `ownership.rs`: since it only borrows
+     * the string, we don't need to get it back, and we can keep using it after the call.
+     */
+
+    {
+        let a_string: String = String::from("Never odd or even");
+
+        let (chars, words, palindrome): (usize, usize, bool) = analyze_string(&a_string);
+
+        println!("{:?} has {} characters and {} words. Is it a palindrome? {}", a_string, chars,
+                 words, palindrome);
+    }
+
+    /* A function can also return a slice of its input. Rust knows that the returned &str points
+     * inside the input, so the input must live at least as long as the slice:
+     */
+
+    {
+        let a_text: String = String::from("Hello there! General Kenobi. You are a bold one.");
+
+        println!("{:?}", trim_to_first_sentence(&a_text));
+        println!("{:?}", trim_to_first_sentence("No punctuation here"));
+        println!("There are {} sentences in {:?}", count_sentences(&a_text), a_text);
+        println!("There are {} sentences in {:?}", count_sentences("Wait... What?! Ok"),
+                 "Wait... What?! Ok");
+    }
+
+    /* Instead of looking for the spaces by hand, as in first_word_before_space(), we can let
+     * split_whitespace() give us each word as a slice. The slices point inside the original
+     * string, so no word is copied until we join them in the new String:
+     */
+
+    {
+        println!("{:?}", reverse_words("the quick brown fox"));
+        println!("{:?}", reverse_words("   hello    there  "));
+    }
+}
+
+/// Returns the number of characters, the number of words and whether the string is a palindrome
+/// (ignoring spaces, punctuation and the case of the letters). It only borrows the string, so the
+/// caller keeps the ownership.
+fn analyze_string(s: &str) -> (usize, usize, bool) {
+    let letters: Vec<char> = s
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+    let palindrome: bool = letters.iter().eq(letters.iter().rev());
+
+    (s.chars().count(), s.split_whitespace().count(), palindrome)
+}
+
+/// Returns the first sentence of `s`, up to and including the first `.`, `!` or `?`. If there is
+/// none of them, the whole string is returned.
+fn trim_to_first_sentence(s: &str) -> &str {
+    match s.find(['.', '!', '?']) {
+        // The terminators are ASCII characters, so they are one byte long
+        Some(index) => &s[..=index],
+        None => s,
+    }
+}
+
+/// Counts the sentences in `text`, where a sentence ends with `.`, `!` or `?`. Only the pieces with
+/// some actual text count, so `"Wait..."` is one sentence and not three, while some text at the end
+/// without a terminator still counts as a sentence.
+fn count_sentences(text: &str) -> usize {
+    text.split(['.', '!', '?'])
+        .filter(|piece| !piece.trim().is_empty())
+        .count()
+}
+
+/// Returns the words of `s` in the opposite order, separated by a single space. Spaces at the
+/// start, at the end or repeated between two words are dropped.
+pub fn reverse_words(s: &str) -> String {
+    let words: Vec<&str> = s.split_whitespace().rev().collect();
+
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analyze_string_counts_and_finds_palindromes() {
+        assert_eq!(analyze_string("Never odd or even"), (17, 4, true));
+        assert_eq!(analyze_string("hello world"), (11, 2, false));
+        assert_eq!(analyze_string(""), (0, 0, true));
+    }
+
+    #[test]
+    fn analyze_string_leaves_the_string_to_the_caller() {
+        let s: String = String::from("A man, a plan, a canal: Panama");
+
+        let (chars, words, palindrome): (usize, usize, bool) = analyze_string(&s);
+        assert_eq!((chars, words, palindrome), (30, 7, true));
+
+        // `s` was only borrowed, so we can still use it
+        assert_eq!(s.len(), 30);
+        assert_eq!(analyze_string(&s), (chars, words, palindrome));
+    }
+
+    #[test]
+    fn trim_to_first_sentence_of_many_sentences() {
+        assert_eq!(trim_to_first_sentence("Hi there! How are you? Fine."), "Hi there!");
+        assert_eq!(trim_to_first_sentence("Really? Yes."), "Really?");
+    }
+
+    #[test]
+    fn trim_to_first_sentence_without_punctuation() {
+        assert_eq!(trim_to_first_sentence("no punctuation here"), "no punctuation here");
+        assert_eq!(trim_to_first_sentence(""), "");
+    }
+
+    #[test]
+    fn trim_to_first_sentence_borrows_from_the_input() {
+        let text: String = String::from("Perché? Così.");
+        let first: &str = trim_to_first_sentence(&text);
+
+        assert_eq!(first, "Perché?");
+        assert_eq!(first.as_ptr(), text.as_ptr());
+    }
+
+    #[test]
+    fn count_sentences_of_a_paragraph() {
+        assert_eq!(count_sentences("Hi there! How are you? I'm fine."), 3);
+    }
+
+    #[test]
+    fn count_sentences_with_text_at_the_end() {
+        assert_eq!(count_sentences("One. Two"), 2);
+        assert_eq!(count_sentences("no punctuation"), 1);
+        assert_eq!(count_sentences(""), 0);
+        assert_eq!(count_sentences("   "), 0);
+    }
+
+    #[test]
+    fn count_sentences_with_repeated_terminators() {
+        assert_eq!(count_sentences("Wait... What?!"), 2);
+        assert_eq!(count_sentences("?!."), 0);
+    }
+
+    #[test]
+    fn reverse_words_of_a_sentence() {
+        assert_eq!(reverse_words("the quick brown fox"), "fox brown quick the");
+        assert_eq!(reverse_words("alone"), "alone");
+    }
+
+    #[test]
+    fn reverse_words_collapses_extra_spaces() {
+        assert_eq!(reverse_words("  the   quick\tbrown fox \n"), "fox brown quick the");
+        assert_eq!(reverse_words("   "), "");
+        assert_eq!(reverse_words(""), "");
+    }
+}
@@ -0,0 +1,173 @@
+use std::cell::RefCell;
+use std::ops::Deref;
+use std::rc::Rc;
+
+/// The ownership rules say that when the owner goes out of scope the value is dropped, but so far
+/// we had to take it on faith. By implementing the **`Drop`** trait we can run our own code right
+/// before a value gets dropped, and so we can see exactly when it happens.
+///
+/// The data structures that own some memory and free it in their `drop()` (like `String`, `Vec`
+/// and `Box`) are called **smart pointers**.
+pub(crate) fn smart_pointers() {
+    /* Each pointer writes its name in a shared log when it's dropped. The log is shared between
+     * us and all the pointers, so it's an `Rc<RefCell<...>>`: `Rc` allows many owners, while
+     * `RefCell` allows to modify the vector even if no one owns it alone.
+     */
+
+    let log: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+
+    {
+        let _first: CustomSmartPointer = CustomSmartPointer::new("first", &log);
+        let _second: CustomSmartPointer = CustomSmartPointer::new("second", &log);
+        println!("Two CustomSmartPointers created");
+
+        // Here the scope ends: the variables are dropped in reverse order of declaration
+    }
+
+    println!("Drop order: {:?}", log.borrow());
+
+    /* We can't call `drop()` ourselves, since Rust would call it again at the end of the scope and
+     * free the same memory twice. To drop a value early we use `std::mem::drop`, which takes the
+     * ownership of the value, so that it can't be used (nor dropped) anymore:
+     */
+
+    log.borrow_mut().clear();
+
+    {
+        let early: CustomSmartPointer = CustomSmartPointer::new("early", &log);
+        let _late: CustomSmartPointer = CustomSmartPointer::new("late", &log);
+
+        drop(early);
+        println!("Dropped before the end of the scope");
+    }
+
+    println!("Drop order: {:?}", log.borrow());
+
+    /* The other trait that makes a smart pointer is `Deref`, which lets us use the `*` operator on
+     * it, exactly as we do with a reference. It also enables **deref coercion**: a `&MyBox<String>`
+     * becomes a `&String`, and then a `&str`, whenever a function asks for one.
+     */
+
+    {
+        let five: MyBox<i32> = MyBox::new(5);
+        println!("*five = {}", *five);     // Behind the scenes this is *(five.deref())
+
+        let name: MyBox<String> = MyBox::new(String::from("Rust"));
+        hello(&name);
+    }
+}
+
+/// A struct that tells the world when it gets dropped, both on the console and in a shared log
+pub(crate) struct CustomSmartPointer {
+    name: String,
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+impl CustomSmartPointer {
+    pub(crate) fn new(name: &str, log: &Rc<RefCell<Vec<String>>>) -> CustomSmartPointer {
+        CustomSmartPointer {
+            name: name.to_string(),
+            log: Rc::clone(log),
+        }
+    }
+}
+
+impl Drop for CustomSmartPointer {
+    fn drop(&mut self) {
+        println!("Dropping CustomSmartPointer {:?}", self.name);
+        self.log.borrow_mut().push(self.name.clone());
+    }
+}
+
+/// A tuple struct holding one value, like a very simple `Box`. The difference is that this one
+/// keeps the value on the stack, but that doesn't matter for `Deref`.
+pub(crate) struct MyBox<T>(T);
+
+impl<T> MyBox<T> {
+    pub(crate) fn new(value: T) -> MyBox<T> {
+        MyBox(value)
+    }
+}
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+fn hello(name: &str) {
+    println!("Hello, {}!", name);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_are_dropped_in_reverse_order_of_declaration() {
+        let log: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+
+        {
+            let _first: CustomSmartPointer = CustomSmartPointer::new("first", &log);
+            let _second: CustomSmartPointer = CustomSmartPointer::new("second", &log);
+            let _third: CustomSmartPointer = CustomSmartPointer::new("third", &log);
+
+            assert!(log.borrow().is_empty());
+        }
+
+        assert_eq!(*log.borrow(), vec!["third", "second", "first"]);
+    }
+
+    #[test]
+    fn mem_drop_drops_a_value_early() {
+        let log: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+
+        {
+            let early: CustomSmartPointer = CustomSmartPointer::new("early", &log);
+            let _late: CustomSmartPointer = CustomSmartPointer::new("late", &log);
+
+            drop(early);
+            assert_eq!(*log.borrow(), vec!["early"]);
+        }
+
+        assert_eq!(*log.borrow(), vec!["early", "late"]);
+    }
+
+    #[test]
+    fn moving_a_value_moves_its_drop() {
+        let log: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+        let outer: CustomSmartPointer;
+
+        {
+            let inner: CustomSmartPointer = CustomSmartPointer::new("moved", &log);
+            outer = inner;
+        }
+
+        assert!(log.borrow().is_empty());
+        drop(outer);
+        assert_eq!(*log.borrow(), vec!["moved"]);
+    }
+
+    #[test]
+    fn my_box_dereferences_to_its_value() {
+        let five: MyBox<i32> = MyBox::new(5);
+
+        assert_eq!(*five, 5);
+        assert_eq!(*five.deref(), 5);
+    }
+
+    #[test]
+    fn my_box_coerces_to_a_str() {
+        /// Only accepts a `&str`, so any other argument has to go through deref coercion
+        fn length(text: &str) -> usize {
+            text.len()
+        }
+
+        let name: MyBox<String> = MyBox::new(String::from("Rust"));
+
+        assert_eq!(length(&name), 4);
+        assert_eq!(name.to_uppercase(), "RUST");
+    }
+}
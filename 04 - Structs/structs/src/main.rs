@@ -1,10 +1,13 @@
 mod structs_general;
 mod structs_ownership;
+mod shapes;
 
 use structs_general::structs_general;
 use structs_ownership::structs_ownership_and_examples;
+use shapes::shapes;
 
 fn main() {
     structs_general();
-    structs_ownership_and_examples()
-}
\ No newline at end of file
+    structs_ownership_and_examples();
+    shapes()
+}
@@ -0,0 +1,223 @@
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+
+/// Before we mentioned traits a couple of times, like `Debug`. A **trait** defines some behaviour
+/// that different types can share: it's similar to an interface in Java. Here the [`Shape`] trait
+/// says that every shape must be able to compute its area.
+pub(crate) trait Shape {
+    fn area(&self) -> f64;
+}
+
+/* The shapes here use f64 for their sides, so this Rectangle is a different struct from the one in
+ * `structs_ownership`: since they are in different modules, they can have the same name.
+ */
+
+struct Rectangle {
+    width: f64,
+    height: f64,
+}
+
+struct Circle {
+    radius: f64,
+}
+
+struct Triangle {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+}
+
+impl Shape for Triangle {
+    /// Area given the three sides, with Heron's formula
+    fn area(&self) -> f64 {
+        let s: f64 = (self.a + self.b + self.c) / 2.0;
+        (s * (s - self.a) * (s - self.b) * (s - self.c)).sqrt()
+    }
+}
+
+/// Describes which shape we want to build, together with its measures
+pub(crate) enum ShapeKind {
+    Rectangle { w: f64, h: f64 },
+    Circle { r: f64 },
+    Triangle { a: f64, b: f64, c: f64 },
+}
+
+/// Builds the shape described by `kind`. Since each shape is a different type, we return a
+/// `Box<dyn Shape>`: a pointer to "something that implements `Shape`", whatever it is.
+pub(crate) fn make_shape(kind: ShapeKind) -> Box<dyn Shape> {
+    match kind {
+        ShapeKind::Rectangle { w, h } => Box::new(Rectangle { width: w, height: h }),
+        ShapeKind::Circle { r } => Box::new(Circle { radius: r }),
+        ShapeKind::Triangle { a, b, c } => Box::new(Triangle { a, b, c }),
+    }
+}
+
+/// The errors that may occur when reading a shape from a text description
+#[derive(Debug, PartialEq)]
+pub(crate) enum ShapeParseError {
+    Empty,
+    UnknownShape(String),
+    WrongArgumentCount { expected: usize, found: usize },
+    InvalidNumber(String),
+}
+
+/// Reads a shape from a description such as `"rect 3 4"`, `"circle 2.5"` or `"tri 3 4 5"`: first
+/// comes the name of the shape, then its measures, all separated by whitespaces
+pub(crate) fn parse_shape(input: &str) -> Result<ShapeKind, ShapeParseError> {
+    let mut words = input.split_whitespace();
+    let name: &str = words.next().ok_or(ShapeParseError::Empty)?;
+
+    let expected: usize = match name {
+        "rect" => 2,
+        "circle" => 1,
+        "tri" => 3,
+        _ => return Err(ShapeParseError::UnknownShape(name.to_string())),
+    };
+
+    let mut numbers: Vec<f64> = Vec::new();
+    for word in words {
+        match word.parse::<f64>() {
+            Ok(number) => numbers.push(number),
+            Err(_) => return Err(ShapeParseError::InvalidNumber(word.to_string())),
+        }
+    }
+
+    if numbers.len() != expected {
+        return Err(ShapeParseError::WrongArgumentCount { expected, found: numbers.len() });
+    }
+
+    Ok(match name {
+        "rect" => ShapeKind::Rectangle { w: numbers[0], h: numbers[1] },
+        "circle" => ShapeKind::Circle { r: numbers[0] },
+        _ => ShapeKind::Triangle { a: numbers[0], b: numbers[1], c: numbers[2] },
+    })
+}
+
+/// Sorts the shapes by their area, from the smallest to the biggest. Floating point numbers don't
+/// implement `Ord` (because `NaN` is not comparable with anything), so we use `sort_by` with
+/// `partial_cmp`, and we put the shapes with a `NaN` area (like an impossible triangle) at the end.
+pub(crate) fn sort_by_area(shapes: &mut [Box<dyn Shape>]) {
+    shapes.sort_by(|a, b| {
+        let (a, b): (f64, f64) = (a.area(), b.area());
+
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.partial_cmp(&b).unwrap(),
+        }
+    });
+}
+
+pub(crate) fn shapes() {
+    {
+        let mut shapes: Vec<Box<dyn Shape>> = vec![
+            make_shape(ShapeKind::Rectangle { w: 3.0, h: 4.0 }),
+            make_shape(ShapeKind::Circle { r: 1.0 }),
+            make_shape(ShapeKind::Triangle { a: 3.0, b: 4.0, c: 5.0 }),
+        ];
+
+        // We don't know which shape each element is, but we can still ask for its area
+        for shape in &shapes {
+            println!("The area of the shape is {:.2}", shape.area());
+        }
+
+        // A triangle with sides 1, 1 and 5 can't exist, so its area is NaN
+        shapes.push(make_shape(ShapeKind::Triangle { a: 1.0, b: 1.0, c: 5.0 }));
+        shapes.push(make_shape(ShapeKind::Rectangle { w: 0.5, h: 2.0 }));
+        sort_by_area(&mut shapes);
+
+        let areas: Vec<f64> = shapes.iter().map(|shape| shape.area()).collect();
+        println!("Sorted areas: {:.2?}", areas);
+    }
+
+    // Thanks to `parse_shape` we can also build shapes from some text, like the user's input
+
+    {
+        let descriptions: [&str; 6] = ["rect 3 4", "circle 2.5", "tri 3 4 5", "hexagon 2", "rect 3",
+                                       "tri 3 x 5"];
+
+        for description in descriptions {
+            match parse_shape(description) {
+                Ok(kind) => println!("{:?} has an area of {:.2}", description,
+                                     make_shape(kind).area()),
+                Err(error) => println!("{:?} is not valid: {:?}", description, error),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn make_shape_builds_a_rectangle() {
+        assert_eq!(make_shape(ShapeKind::Rectangle { w: 3.0, h: 4.0 }).area(), 12.0);
+    }
+
+    #[test]
+    fn make_shape_builds_a_circle() {
+        assert_eq!(make_shape(ShapeKind::Circle { r: 2.0 }).area(), 4.0 * PI);
+    }
+
+    #[test]
+    fn make_shape_builds_a_triangle() {
+        assert_eq!(make_shape(ShapeKind::Triangle { a: 3.0, b: 4.0, c: 5.0 }).area(), 6.0);
+    }
+
+    #[test]
+    fn parse_shape_reads_each_shape() {
+        assert!(matches!(parse_shape("rect 3 4"), Ok(ShapeKind::Rectangle { w: 3.0, h: 4.0 })));
+        assert!(matches!(parse_shape("circle 2.5"), Ok(ShapeKind::Circle { r: 2.5 })));
+        assert!(matches!(parse_shape("  tri 3   4 5 "),
+                         Ok(ShapeKind::Triangle { a: 3.0, b: 4.0, c: 5.0 })));
+    }
+
+    #[test]
+    fn parse_shape_rejects_malformed_descriptions() {
+        assert!(matches!(parse_shape(""), Err(ShapeParseError::Empty)));
+        assert!(matches!(parse_shape("   "), Err(ShapeParseError::Empty)));
+        assert!(matches!(parse_shape("rect 3"),
+                         Err(ShapeParseError::WrongArgumentCount { expected: 2, found: 1 })));
+        assert!(matches!(parse_shape("circle 1 2"),
+                         Err(ShapeParseError::WrongArgumentCount { expected: 1, found: 2 })));
+        assert!(matches!(parse_shape("tri 3 x 5"),
+                         Err(ShapeParseError::InvalidNumber(word)) if word == "x"));
+    }
+
+    #[test]
+    fn parse_shape_rejects_unknown_shapes() {
+        assert!(matches!(parse_shape("hexagon 2"),
+                         Err(ShapeParseError::UnknownShape(name)) if name == "hexagon"));
+    }
+
+    #[test]
+    fn sort_by_area_orders_a_mixed_vector() {
+        let mut shapes: Vec<Box<dyn Shape>> = vec![
+            make_shape(ShapeKind::Rectangle { w: 3.0, h: 4.0 }),
+            make_shape(ShapeKind::Triangle { a: 1.0, b: 1.0, c: 5.0 }),
+            make_shape(ShapeKind::Circle { r: 1.0 }),
+            make_shape(ShapeKind::Triangle { a: 3.0, b: 4.0, c: 5.0 }),
+            make_shape(ShapeKind::Rectangle { w: 0.5, h: 2.0 }),
+        ];
+
+        sort_by_area(&mut shapes);
+        let areas: Vec<f64> = shapes.iter().map(|shape| shape.area()).collect();
+
+        assert_eq!(&areas[..4], &[1.0, PI, 6.0, 12.0]);
+        assert!(areas[4].is_nan());
+    }
+}
@@ -160,7 +160,38 @@ pub(crate) fn structs_ownership_and_examples() {
                  my_square.width, my_square.area());
     }
 
-    // Each struct can have multiple `impl` blocks
+    // Each struct can have multiple `impl` blocks. The second one, below, reduces the aspect ratio:
+
+    {
+        let a_screen: Rectangle = Rectangle::new(1920, 1080);
+        let (w, h): (u32, u32) = a_screen.aspect_ratio_reduced();
+
+        println!("A 1920x1080 screen has an aspect ratio of {}:{}", w, h);
+    }
+
+    // Functions can also return a collection of structs, like all the rectangles with a given area
+
+    {
+        for rectangle in rectangles_with_area(12) {
+            println!("{}x{} has an area of {}", rectangle.width, rectangle.height,
+                     rectangle.area());
+        }
+    }
+
+    // This block exists only when the `serde` feature is enabled
+
+    #[cfg(feature = "serde")]
+    {
+        let json: String = to_json(&Rectangle::new(30, 50));
+        println!("As JSON: {}", json);
+
+        let back: Rectangle = from_json(&json).expect("the JSON was written by to_json");
+        println!("Back from JSON: {}x{}", back.width, back.height);
+
+        if let Err(error) = from_json("{\"width\": 30}") {
+            println!("Not a rectangle: {}", error);
+        }
+    }
 }
 
 fn get_area(width: i32, height: i32) -> i32 {
@@ -171,7 +202,12 @@ fn get_area_tuples(dimensions: (i32, i32)) -> i32 {
     dimensions.0 * dimensions.1
 }
 
-struct Rectangle {
+/* With the `serde` feature enabled (`cargo run --features serde`), the `cfg_attr` below becomes
+ * `#[derive(Serialize, Deserialize)]`, so that the rectangle can be converted to and from JSON.
+ * Without the feature the line is ignored, and serde isn't even compiled.
+ */
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Rectangle {
     width: u32,
     height: u32,
 }
@@ -207,10 +243,139 @@ impl Rectangle {
     }
 }
 
+impl Rectangle {
+    /// Returns the width and the height divided by their greatest common divisor, so that for
+    /// instance a 1920x1080 rectangle becomes 16:9. If one of the sides is 0, the sides are
+    /// returned as they are.
+    fn aspect_ratio_reduced(&self) -> (u32, u32) {
+        if self.width == 0 || self.height == 0 {
+            return (self.width, self.height);
+        }
+
+        let divisor: u32 = gcd(self.width, self.height);
+        (self.width / divisor, self.height / divisor)
+    }
+}
+
+/// Returns all the rectangles with integer sides whose area is `area`. Since a 3x4 rectangle is
+/// the same as a 4x3 one, only the rectangles with `width <= height` are returned.
+fn rectangles_with_area(area: u32) -> Vec<Rectangle> {
+    let mut rectangles: Vec<Rectangle> = Vec::new();
+    let mut width: u32 = 1;
+
+    // `width * width <= area` would overflow for the biggest areas, dividing can't
+    while width <= area / width {
+        if area.is_multiple_of(width) {
+            rectangles.push(Rectangle::new(width, area / width));
+        }
+        width += 1;
+    }
+
+    rectangles
+}
+
+/// Greatest common divisor, computed with the Euclidean algorithm
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Writes the rectangle as a JSON object, such as `{"width":30,"height":50}`
+#[cfg(feature = "serde")]
+pub(crate) fn to_json(r: &Rectangle) -> String {
+    // Two numbers can always be written as JSON, so this can't fail
+    serde_json::to_string(r).expect("a Rectangle is always valid JSON")
+}
+
+/// Reads a rectangle from a JSON object with a `width` and a `height`
+#[cfg(feature = "serde")]
+pub(crate) fn from_json(s: &str) -> Result<Rectangle, serde_json::Error> {
+    serde_json::from_str(s)
+}
+
 fn get_area_rectangle(rectangle: &Rectangle) -> u32 { rectangle.width * rectangle.height }
 
 #[derive(Debug)]
 struct DebugRectangle {
     width: u32,
     height: u32,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aspect_ratio_of_a_full_hd_screen() {
+        assert_eq!(Rectangle::new(1920, 1080).aspect_ratio_reduced(), (16, 9));
+    }
+
+    #[test]
+    fn aspect_ratio_of_a_square() {
+        assert_eq!(Rectangle::square(7).aspect_ratio_reduced(), (1, 1));
+    }
+
+    #[test]
+    fn aspect_ratio_with_a_zero_side() {
+        assert_eq!(Rectangle::new(30, 0).aspect_ratio_reduced(), (30, 0));
+        assert_eq!(Rectangle::new(0, 0).aspect_ratio_reduced(), (0, 0));
+    }
+
+    fn sides(rectangles: Vec<Rectangle>) -> Vec<(u32, u32)> {
+        rectangles.iter().map(|r| (r.width, r.height)).collect()
+    }
+
+    #[test]
+    fn rectangles_with_a_composite_area() {
+        assert_eq!(sides(rectangles_with_area(12)), vec![(1, 12), (2, 6), (3, 4)]);
+    }
+
+    #[test]
+    fn rectangles_with_a_prime_area() {
+        assert_eq!(sides(rectangles_with_area(13)), vec![(1, 13)]);
+    }
+
+    #[test]
+    fn rectangles_with_no_area() {
+        assert!(rectangles_with_area(0).is_empty());
+    }
+
+    #[test]
+    fn rectangles_with_the_biggest_area_do_not_overflow() {
+        let rectangles: Vec<Rectangle> = rectangles_with_area(u32::MAX);
+
+        assert_eq!(sides(rectangles).first(), Some(&(1, u32::MAX)));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn to_json_writes_both_sides() {
+        assert_eq!(to_json(&Rectangle::new(30, 50)), "{\"width\":30,\"height\":50}");
+    }
+
+    #[test]
+    fn from_json_reads_back_what_to_json_writes() {
+        for (width, height) in [(30, 50), (0, 0), (1, u32::MAX)] {
+            let back: Rectangle = from_json(&to_json(&Rectangle::new(width, height))).unwrap();
+            assert_eq!((back.width, back.height), (width, height));
+        }
+    }
+
+    #[test]
+    fn from_json_ignores_spaces_and_the_order_of_the_fields() {
+        let rectangle: Rectangle = from_json("{ \"height\": 50, \"width\": 30 }").unwrap();
+
+        assert_eq!((rectangle.width, rectangle.height), (30, 50));
+    }
+
+    #[test]
+    fn from_json_rejects_invalid_rectangles() {
+        assert!(from_json("{\"width\": 30}").is_err());
+        assert!(from_json("{\"width\": -1, \"height\": 50}").is_err());
+        assert!(from_json("{\"width\": 30, \"height\": 4294967296}").is_err());
+        assert!(from_json("not json").is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The states that mint euro coins. Each coin carries the design of the state that minted it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EUStates {
+    Italy,
+    France,
+    Belgium,
+    Germany,
+    Spain,
+    Portugal,
+    Netherlands,
+    Sweden,
+    // and so on...
+}
+
+/// The three kinds of euros: the cent coins and the euro coins come from a specific state, while
+/// the banknotes are the same everywhere.
+#[derive(Debug)]
+pub enum Euros {
+    CentCoin(EUStates),
+    EuroCoin(EUStates),
+    EuroBanknote,
+}
+
+impl EUStates {
+    /// Returns the two-letter ISO 3166 code of the state
+    pub fn iso_code(&self) -> &'static str {
+        match self {
+            EUStates::Italy => "IT",
+            EUStates::France => "FR",
+            EUStates::Belgium => "BE",
+            EUStates::Germany => "DE",
+            EUStates::Spain => "ES",
+            EUStates::Portugal => "PT",
+            EUStates::Netherlands => "NL",
+            EUStates::Sweden => "SE",
+        }
+    }
+}
+
+/// Returns every variant of [`EUStates`]. Rust can't iterate over the variants of an enum by
+/// itself, so we start from the first state and follow [`next_state`] until the last one.
+pub fn all_states() -> Vec<EUStates> {
+    std::iter::successors(Some(EUStates::Italy), |&state| next_state(state)).collect()
+}
+
+/// Returns the state that follows `state` in [`all_states`], or `None` after the last one. The
+/// `match` has no `_` arm, so a new state that isn't given a place in the list won't compile.
+fn next_state(state: EUStates) -> Option<EUStates> {
+    match state {
+        EUStates::Italy => Some(EUStates::France),
+        EUStates::France => Some(EUStates::Belgium),
+        EUStates::Belgium => Some(EUStates::Germany),
+        EUStates::Germany => Some(EUStates::Spain),
+        EUStates::Spain => Some(EUStates::Portugal),
+        EUStates::Portugal => Some(EUStates::Netherlands),
+        EUStates::Netherlands => Some(EUStates::Sweden),
+        EUStates::Sweden => None,
+    }
+}
+
+/// Picks a random state, using the thread-local random number generator
+pub fn random_state() -> EUStates {
+    pick_state(&mut rand::thread_rng())
+}
+
+/// Picks a random state with a generator built from `seed`: the same seed always gives the same
+/// state, which is handy when we want a reproducible demo
+pub fn random_state_seeded(seed: u64) -> EUStates {
+    pick_state(&mut StdRng::seed_from_u64(seed))
+}
+
+fn pick_state(rng: &mut impl Rng) -> EUStates {
+    let states: Vec<EUStates> = all_states();
+    states[rng.gen_range(0..states.len())]
+}
+
+/// Produces a random coin or banknote with a generator built from `seed`. Coins also get a random
+/// state of origin, while banknotes don't have one at all: that's guaranteed by the enum itself.
+pub fn random_coin_seeded(seed: u64) -> Euros {
+    let mut rng: StdRng = StdRng::seed_from_u64(seed);
+
+    match rng.gen_range(0..3) {
+        0 => Euros::CentCoin(pick_state(&mut rng)),
+        1 => Euros::EuroCoin(pick_state(&mut rng)),
+        _ => Euros::EuroBanknote,
+    }
+}
+
+/// Returns the value in cents of a coin. Each variant covers several denominations, so we take the
+/// smallest one as representative: 1 cent, 1 euro and 5 euros.
+pub fn coin_value_cents(coin: &Euros) -> u32 {
+    match coin {
+        Euros::CentCoin(_) => 1,
+        Euros::EuroCoin(_) => 100,
+        Euros::EuroBanknote => 500,
+    }
+}
+
+/// Returns the total value in cents of all the coins and banknotes in the purse
+pub fn purse_total(coins: &[Euros]) -> u32 {
+    coins.iter().map(coin_value_cents).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn all_states_lists_every_state_once() {
+        let states: Vec<EUStates> = all_states();
+        let unique: HashSet<EUStates> = states.iter().copied().collect();
+
+        assert_eq!(states.len(), 8);
+        assert_eq!(unique.len(), states.len());
+    }
+
+    #[test]
+    fn iso_codes_are_unique() {
+        let codes: HashSet<&str> = all_states().iter().map(EUStates::iso_code).collect();
+
+        assert_eq!(codes.len(), all_states().len());
+        assert!(codes.iter().all(|code| code.len() == 2));
+    }
+
+    #[test]
+    fn random_state_seeded_is_deterministic() {
+        for seed in 0..20 {
+            assert_eq!(random_state_seeded(seed), random_state_seeded(seed));
+        }
+    }
+
+    #[test]
+    fn random_state_is_one_of_the_states() {
+        assert!(all_states().contains(&random_state()));
+    }
+
+    #[test]
+    fn random_coin_seeded_is_deterministic() {
+        for seed in 0..20 {
+            let first: String = format!("{:?}", random_coin_seeded(seed));
+            assert_eq!(format!("{:?}", random_coin_seeded(seed)), first);
+        }
+    }
+
+    #[test]
+    fn random_coin_seeded_gives_every_kind() {
+        let coins: Vec<Euros> = (0..100).map(random_coin_seeded).collect();
+
+        assert!(coins.iter().any(|coin| matches!(coin, Euros::CentCoin(_))));
+        assert!(coins.iter().any(|coin| matches!(coin, Euros::EuroCoin(_))));
+        assert!(coins.iter().any(|coin| matches!(coin, Euros::EuroBanknote)));
+    }
+
+    #[test]
+    fn coin_value_of_each_kind() {
+        assert_eq!(coin_value_cents(&Euros::CentCoin(EUStates::Italy)), 1);
+        assert_eq!(coin_value_cents(&Euros::EuroCoin(EUStates::France)), 100);
+        assert_eq!(coin_value_cents(&Euros::EuroBanknote), 500);
+    }
+
+    #[test]
+    fn purse_total_sums_a_mixed_purse() {
+        let purse: [Euros; 5] = [
+            Euros::CentCoin(EUStates::Italy),
+            Euros::CentCoin(EUStates::Spain),
+            Euros::EuroCoin(EUStates::Germany),
+            Euros::EuroBanknote,
+            Euros::EuroBanknote,
+        ];
+
+        assert_eq!(purse_total(&purse), 1102);
+        assert_eq!(purse_total(&[]), 0);
+    }
+}
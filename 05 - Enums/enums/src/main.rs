@@ -1,6 +1,18 @@
 use std::f32::consts::E;
 use std::net::Ipv6Addr;
 
+mod coins;
+mod message;
+mod money;
+mod traffic_light;
+
+use coins::{all_states, purse_total, random_coin_seeded, random_state, random_state_seeded};
+use coins::{EUStates, Euros};
+use message::{count_moves, cursor_from_string, cursor_to_string, run_messages};
+use message::{ConsoleVisitor, Cursor, Message};
+use money::{parse_money, Money};
+use traffic_light::{simulate, TrafficLight};
+
 /// Rust allows the use of enumerations (or enums), which are a way to describe a type by
 /// enumerating all the possible variants of such type.
 fn main() {
@@ -37,10 +49,10 @@ fn main() {
                 // Some code here...
             }
 
-            // Specific parameter
-            fn check_v6_address(ip_address: &IPAddress::V6) {
+            // Specific parameter (commented, since a variant is not a type and it wouldn't compile)
+            /* fn check_v6_address(ip_address: &IPAddress::V6) {
                 // Some code here...
-            }
+            } */
 
             check_address(&addr_v4);
             check_address(&addr_v6);
@@ -128,18 +140,20 @@ fn main() {
         }
     }
 
-    // Let's analyze the following enumeration:
-
-    #[derive(Debug)]
-    enum Message {
-        Quit,
-        Move {
-            x: i32,
-            y: i32,
-        },
-        Write(String),
-        ChangeColor(i32, i32, i32, i32),
-    }
+    /* Let's analyze the following enumeration (it's defined in `message.rs`, so that we can use it
+     * in some more examples later on):
+     *
+     * #[derive(Debug)]
+     * enum Message {
+     *      Quit,
+     *      Move {
+     *          x: i32,
+     *          y: i32,
+     *      },
+     *      Write(String),
+     *      ChangeColor(i32, i32, i32, i32),
+     * }
+     */
 
     /* Such enumerations is quite interesting, let's see all the fields one by one:
      *  - Quit: has no data associated with it;
@@ -163,18 +177,42 @@ fn main() {
     }
 
     /* We can use `impl` not only with structs, but also with enums. This allows to extend even
-     * more the possibilities of the enums.
+     * more the possibilities of the enums. Since Message lives in `message.rs`, its `impl` is
+     * there too:
+     *
+     * impl Message {
+     *      fn send_to_console(&self) {
+     *          println!("{:?}", self);
+     *      }
+     * }
      */
 
-    impl Message {
-        fn send_to_console (&self) {
-            println!("{:?}", self);
-        }
-    }
-
     {
         let my_message: Message = Message::Write(String::from("Houston, we got a problem..."));
         my_message.send_to_console();
+
+        /* Instead of matching on the message every time, we can also let a "visitor" handle each
+         * variant. ConsoleVisitor turns each message into a line of text:
+         */
+
+        let messages: [Message; 4] = [Message::Quit,
+                                      Message::Move { x: 3, y: -2 },
+                                      my_message,
+                                      Message::ChangeColor(255, 128, 0, 255)];
+        for message in &messages {
+            println!("{}", message.accept(&ConsoleVisitor));
+        }
+        println!("Move messages in the log: {}", count_moves(&messages));
+
+        // The messages can also drive a cursor around the screen
+        let start: Cursor = Cursor { x: 0, y: 0, color: (0, 0, 0, 255) };
+        let end: Cursor = run_messages(start, &messages);
+        println!("{:?}", end);
+
+        // We can save the cursor as a string, and read it back
+        let saved: String = cursor_to_string(&end);
+        println!("Saved as {:?}, read back as {:?}", saved, cursor_from_string(&saved));
+        println!("{:?}", cursor_from_string("3,-2,#FF80"));
     }
 
     /* In a lot of programming languages, there is something similar to a `null` value: in Python is
@@ -269,32 +307,37 @@ fn main() {
             print_values(an_euro_banknote);
         }
 
+        /* A traffic light is an actual FSM, and in `traffic_light.rs` it's written as an enum: the
+         * `next()` method uses a match to decide which state comes after the current one.
+         */
+
+        {
+            let states: Vec<TrafficLight> = simulate(4);
+            let total: u32 = states.iter().map(|light| light.duration_secs()).sum();
+
+            println!("{:?} takes {} seconds", states, total);
+        }
+
         /* Match can also be useful to extract the value of an enum variant. Moreover, match can
          * bind to the values of a particular variant. For instance, let's write again the Euros
          * enum with a little modification: the cents and the coins have different designs depending
          * on the state that minted it. We'll thus have two enums: one for the coins, and one for
-         * the states:
+         * the states. Both of them are in `coins.rs`, so that we can add some functions to them:
+         *
+         * enum EUStates {
+         *      Italy,
+         *      France,
+         *      // and so on...
+         * }
+         *
+         * enum Euros {
+         *      CentCoin(EUStates),
+         *      EuroCoin(EUStates),
+         *      EuroBanknote,
+         * }
          */
 
         {
-            #[derive(Debug)]
-            enum EUStates {
-                Italy,
-                France,
-                Belgium,
-                Germany,
-                Spain,
-                Portugal,
-                Netherlands,
-                Sweden,
-                // and so on...
-            }
-
-            enum Euros {
-                CentCoin(EUStates),
-                EuroCoin(EUStates),
-                EuroBanknote,
-            }
 
             fn return_values(coin: Euros) -> String {
                 match coin {
@@ -319,6 +362,58 @@ fn main() {
             println!("{}", return_values(a_coin));
             println!("{}", return_values(another_coin));
             println!("{}", return_values(a_banknote));
+
+            // Since an enum can't be iterated by itself, all_states() lists all of its variants
+            for state in all_states() {
+                print!("{:?} ({}) ", state, state.iso_code());
+            }
+            println!();
+
+            println!("A random state: {:?} | With seed 21: {:?}", random_state(),
+                     random_state_seeded(21));
+
+            for seed in 0..4 {
+                println!("Random coin with seed {}: {:?}", seed, random_coin_seeded(seed));
+            }
+
+            let purse: Vec<Euros> = vec![Euros::CentCoin(EUStates::Spain),
+                                         Euros::EuroCoin(EUStates::Italy),
+                                         Euros::EuroBanknote,
+                                         Euros::CentCoin(EUStates::Portugal)];
+            println!("The purse holds {} cents", purse_total(&purse));
+        }
+
+        /* Talking about euros, it's a bad idea to store an amount of money with an `f64`, since
+         * floating point numbers are not exact. The Money struct (in `money.rs`) stores the cents
+         * as an integer instead:
+         */
+
+        {
+            println!("With f64: 0.1 + 0.2 = {}", 0.1 + 0.2);
+
+            let total: Money = Money::from_euros(0.1) + Money::from_euros(0.2);
+
+            let change: Money = Money::from_euros(5.0) - total;
+            println!("With Money: 0.1 + 0.2 = {} | Change from 5€: {}", total.to_euros(),
+                     change.to_euros());
+
+            // Splitting 1€ among 3 people gives €0.34, €0.33 and €0.33, and no cent gets lost
+            let parts: Vec<Money> = Money::from_euros(1.0).split(3);
+            for part in &parts {
+                println!("Part: {}", part);
+            }
+
+            // Money also implements Display, so we can print it directly
+            println!("{} | {} | {} | {}", Money::from_euros(12.34), Money::from_euros(0.0),
+                     Money::from_euros(0.05), Money::from_euros(-1.5));
+
+            // ...and we can also read it from a string, getting a Result back
+            for input in ["12.34", "€12.34", "12", "1.234", "twelve"] {
+                match parse_money(input) {
+                    Ok(money) => println!("{:?} is {}", input, money),
+                    Err(error) => println!("{:?} is not valid: {:?}", input, error),
+                }
+            }
         }
 
         /* The match statement can also be used with the Option<T> enum. Since the match statement
@@ -0,0 +1,258 @@
+/// An enumeration whose variants hold different kinds of data (see `main.rs` for the details)
+#[derive(Debug)]
+pub enum Message {
+    Quit,
+    Move {
+        x: i32,
+        y: i32,
+    },
+    Write(String),
+    ChangeColor(i32, i32, i32, i32),
+}
+
+/// A **visitor** has one method for each variant of [`Message`]. This way, instead of writing a
+/// new `match` every time we want to do something with a message, we can write a new visitor, and
+/// the `match` is written only once, inside [`Message::accept`].
+pub trait MessageVisitor {
+    fn visit_quit(&self) -> String;
+    fn visit_move(&self, x: i32, y: i32) -> String;
+    fn visit_write(&self, text: &str) -> String;
+    fn visit_change_color(&self, r: i32, g: i32, b: i32, a: i32) -> String;
+}
+
+impl Message {
+    /// Prints the message with its `Debug` representation
+    pub fn send_to_console(&self) {
+        println!("{:?}", self);
+    }
+
+    /// Calls the method of `visitor` that corresponds to the variant of the message
+    pub fn accept(&self, visitor: &impl MessageVisitor) -> String {
+        match self {
+            Message::Quit => visitor.visit_quit(),
+            Message::Move { x, y } => visitor.visit_move(*x, *y),
+            Message::Write(text) => visitor.visit_write(text),
+            Message::ChangeColor(r, g, b, a) => visitor.visit_change_color(*r, *g, *b, *a),
+        }
+    }
+}
+
+/// Counts how many [`Message::Move`] there are in a log of messages. The `matches!` macro is a
+/// shortcut for a `match` that returns `true` if the pattern matches, and `false` otherwise.
+pub fn count_moves(messages: &[Message]) -> usize {
+    messages
+        .iter()
+        .filter(|message| matches!(message, Message::Move { .. }))
+        .count()
+}
+
+/// A cursor on the screen, with its position and its color (red, green, blue and alpha)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cursor {
+    pub x: i32,
+    pub y: i32,
+    pub color: (u8, u8, u8, u8),
+}
+
+/// Applies each message to the cursor, in order, and returns the final cursor:
+///  - `Move` shifts the position by `x` and `y`, saturating: a cursor that reaches the edge of
+///    the `i32` range stops there instead of overflowing;
+///  - `ChangeColor` sets the color (each component is clamped between 0 and 255);
+///  - `Write` and `Quit` leave the cursor as it is.
+pub fn run_messages(start: Cursor, messages: &[Message]) -> Cursor {
+    let to_u8 = |component: i32| component.clamp(0, 255) as u8;
+    let mut cursor: Cursor = start;
+
+    for message in messages {
+        match message {
+            Message::Move { x, y } => {
+                cursor.x = cursor.x.saturating_add(*x);
+                cursor.y = cursor.y.saturating_add(*y);
+            }
+            Message::ChangeColor(r, g, b, a) => {
+                cursor.color = (to_u8(*r), to_u8(*g), to_u8(*b), to_u8(*a));
+            }
+            Message::Write(_) | Message::Quit => (),
+        }
+    }
+
+    cursor
+}
+
+/// Writes the cursor in a compact form, such as `3,-2,#FF8000FF`
+pub fn cursor_to_string(c: &Cursor) -> String {
+    let (r, g, b, a) = c.color;
+    format!("{},{},#{:02X}{:02X}{:02X}{:02X}", c.x, c.y, r, g, b, a)
+}
+
+/// The errors that may occur while reading a cursor from a string
+#[derive(Debug, PartialEq)]
+pub enum CursorParseError {
+    /// The string doesn't have exactly three comma-separated fields
+    WrongFieldCount(usize),
+    /// The position is not a valid integer
+    InvalidPosition(String),
+    /// The color is not in the `#RRGGBBAA` form
+    InvalidColor(String),
+}
+
+/// Reads a cursor written by [`cursor_to_string`]
+pub fn cursor_from_string(s: &str) -> Result<Cursor, CursorParseError> {
+    let fields: Vec<&str> = s.trim().split(',').collect();
+    if fields.len() != 3 {
+        return Err(CursorParseError::WrongFieldCount(fields.len()));
+    }
+
+    let position = |field: &str| {
+        field
+            .parse::<i32>()
+            .map_err(|_| CursorParseError::InvalidPosition(field.to_string()))
+    };
+    let x: i32 = position(fields[0])?;
+    let y: i32 = position(fields[1])?;
+
+    let invalid_color = || CursorParseError::InvalidColor(fields[2].to_string());
+    let hex: &str = fields[2].strip_prefix('#').ok_or_else(invalid_color)?;
+    if hex.len() != 8 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid_color());
+    }
+
+    let component = |i: usize| {
+        u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid_color())
+    };
+    let color: (u8, u8, u8, u8) = (component(0)?, component(1)?, component(2)?, component(3)?);
+
+    Ok(Cursor { x, y, color })
+}
+
+/// A visitor that describes each message as a line to print on the console
+pub struct ConsoleVisitor;
+
+impl MessageVisitor for ConsoleVisitor {
+    fn visit_quit(&self) -> String {
+        String::from("Quitting...")
+    }
+
+    fn visit_move(&self, x: i32, y: i32) -> String {
+        format!("Moving to ({}, {})", x, y)
+    }
+
+    fn visit_write(&self, text: &str) -> String {
+        format!("Writing {:?}", text)
+    }
+
+    fn visit_change_color(&self, r: i32, g: i32, b: i32, a: i32) -> String {
+        format!("Changing color to rgba({}, {}, {}, {})", r, g, b, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A visitor that only says which method was called, and with which data
+    struct TagVisitor;
+
+    impl MessageVisitor for TagVisitor {
+        fn visit_quit(&self) -> String {
+            String::from("quit")
+        }
+
+        fn visit_move(&self, x: i32, y: i32) -> String {
+            format!("move {} {}", x, y)
+        }
+
+        fn visit_write(&self, text: &str) -> String {
+            format!("write {}", text)
+        }
+
+        fn visit_change_color(&self, r: i32, g: i32, b: i32, a: i32) -> String {
+            format!("color {} {} {} {}", r, g, b, a)
+        }
+    }
+
+    #[test]
+    fn accept_calls_the_method_of_each_variant() {
+        assert_eq!(Message::Quit.accept(&TagVisitor), "quit");
+        assert_eq!(Message::Move { x: 3, y: -4 }.accept(&TagVisitor), "move 3 -4");
+        assert_eq!(Message::Write(String::from("hi")).accept(&TagVisitor), "write hi");
+        assert_eq!(Message::ChangeColor(1, 2, 3, 4).accept(&TagVisitor), "color 1 2 3 4");
+    }
+
+    #[test]
+    fn console_visitor_describes_the_messages() {
+        assert_eq!(Message::Quit.accept(&ConsoleVisitor), "Quitting...");
+        assert_eq!(Message::Move { x: 1, y: 2 }.accept(&ConsoleVisitor), "Moving to (1, 2)");
+        assert_eq!(Message::Write(String::from("hi")).accept(&ConsoleVisitor), "Writing \"hi\"");
+        assert_eq!(Message::ChangeColor(255, 0, 0, 255).accept(&ConsoleVisitor),
+                   "Changing color to rgba(255, 0, 0, 255)");
+    }
+
+    #[test]
+    fn count_moves_in_a_mixed_log() {
+        let messages: [Message; 5] = [
+            Message::Move { x: 1, y: 1 },
+            Message::Write(String::from("hello")),
+            Message::Move { x: -2, y: 0 },
+            Message::Quit,
+            Message::Move { x: 0, y: 5 },
+        ];
+
+        assert_eq!(count_moves(&messages), 3);
+    }
+
+    #[test]
+    fn count_moves_without_moves() {
+        assert_eq!(count_moves(&[Message::Quit, Message::ChangeColor(0, 0, 0, 0)]), 0);
+        assert_eq!(count_moves(&[]), 0);
+    }
+
+    #[test]
+    fn run_messages_gives_the_final_cursor() {
+        let start: Cursor = Cursor { x: 0, y: 0, color: (0, 0, 0, 255) };
+        let messages: [Message; 5] = [
+            Message::Move { x: 3, y: 4 },
+            Message::Write(String::from("ignored")),
+            Message::ChangeColor(255, 128, -5, 300),
+            Message::Move { x: -1, y: -6 },
+            Message::Quit,
+        ];
+
+        assert_eq!(run_messages(start, &messages),
+                   Cursor { x: 2, y: -2, color: (255, 128, 0, 255) });
+    }
+
+    #[test]
+    fn run_messages_saturates_at_the_edges() {
+        let start: Cursor = Cursor { x: i32::MAX - 1, y: i32::MIN + 1, color: (0, 0, 0, 0) };
+        let messages: [Message; 2] = [
+            Message::Move { x: 10, y: -10 },
+            Message::Move { x: 5, y: -5 },
+        ];
+
+        let end: Cursor = run_messages(start, &messages);
+        assert_eq!((end.x, end.y), (i32::MAX, i32::MIN));
+    }
+
+    #[test]
+    fn cursor_round_trips_through_a_string() {
+        let cursor: Cursor = Cursor { x: 3, y: -2, color: (255, 128, 0, 255) };
+        let text: String = cursor_to_string(&cursor);
+
+        assert_eq!(text, "3,-2,#FF8000FF");
+        assert_eq!(cursor_from_string(&text), Ok(cursor));
+    }
+
+    #[test]
+    fn cursor_from_string_rejects_malformed_input() {
+        assert_eq!(cursor_from_string("3,-2"), Err(CursorParseError::WrongFieldCount(2)));
+        assert_eq!(cursor_from_string("a,0,#00000000"),
+                   Err(CursorParseError::InvalidPosition(String::from("a"))));
+        assert_eq!(cursor_from_string("0,0,00000000"),
+                   Err(CursorParseError::InvalidColor(String::from("00000000"))));
+        assert_eq!(cursor_from_string("0,0,#FFF"),
+                   Err(CursorParseError::InvalidColor(String::from("#FFF"))));
+        assert_eq!(cursor_from_string("0,0,#GG000000"),
+                   Err(CursorParseError::InvalidColor(String::from("#GG000000"))));
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+use std::ops::{Add, Sub};
+
+/// Floating point numbers (`f32` and `f64`) can't represent every decimal number exactly: for
+/// instance, `0.1 + 0.2` in `f64` gives `0.30000000000000004`. That's not a big deal for a
+/// physics simulation, but it is for money. A common solution is to store the amount as an integer
+/// number of **cents**, and convert to euros only when showing the value to someone.
+///
+/// [`Money`] is a tuple struct that wraps the number of cents, which may also be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Money(i64);
+
+impl Money {
+    /// Converts an amount of euros into [`Money`], rounding to the nearest cent
+    pub fn from_euros(euros: f64) -> Money {
+        Money((euros * 100.0).round() as i64)
+    }
+
+    /// Converts back the amount of cents into euros
+    pub fn to_euros(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    /// Splits the amount evenly among `n` people. Since cents can't be divided any further, the
+    /// remaining cents are given one by one to the first parts, so that the sum of the parts is
+    /// always the original amount. Splitting among 0 people gives an empty vector.
+    pub fn split(&self, n: u32) -> Vec<Money> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let n: i64 = n as i64;
+        let base: i64 = self.0 / n;
+        let remainder: i64 = self.0 % n;
+
+        (0..n)
+            .map(|i| {
+                if i < remainder.abs() {
+                    Money(base + remainder.signum())
+                } else {
+                    Money(base)
+                }
+            })
+            .collect()
+    }
+}
+
+/* Implementing the `Add` and `Sub` traits allows us to use the `+` and `-` operators between two
+ * instances of Money, exactly as we would do with two integers.
+ */
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0 + other.0)
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money(self.0 - other.0)
+    }
+}
+
+/* By implementing the `Display` trait we can print a Money instance with `{}`, as we would do with
+ * a String. The sign goes before the euro symbol, so we get `-€1.50` and not `€-1.50`.
+ */
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign: &str = if self.0 < 0 { "-" } else { "" };
+        let cents: u64 = self.0.unsigned_abs();
+
+        write!(f, "{}€{}.{:02}", sign, cents / 100, cents % 100)
+    }
+}
+
+/// The errors that may occur while parsing a string into [`Money`]
+#[derive(Debug, PartialEq)]
+pub enum MoneyParseError {
+    /// The string has no digits at all
+    Empty,
+    /// The string contains something that is not a digit (or the wrong number of `.`)
+    InvalidNumber,
+    /// The string has more than two decimal places, so it's more precise than a cent
+    TooManyDecimals,
+    /// The amount is too big to be stored as a number of cents in an `i64`
+    Overflow,
+}
+
+/// Parses an amount of euros, such as `"12.34"`, `"€12.34"` or `"12"` (which means `12.00`), into
+/// [`Money`]. A leading `-` is accepted as well, so that what gets printed by `Display` can be read
+/// back. The parsing is done on the digits directly, without passing through an `f64`.
+pub fn parse_money(s: &str) -> Result<Money, MoneyParseError> {
+    let s: &str = s.trim();
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let s: &str = s.strip_prefix('€').unwrap_or(s);
+
+    if s.is_empty() {
+        return Err(MoneyParseError::Empty);
+    }
+
+    let (whole, fraction) = match s.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (s, "00"),
+    };
+
+    let all_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+
+    if !all_digits(whole) || !all_digits(fraction) {
+        return Err(MoneyParseError::InvalidNumber);
+    }
+    if fraction.len() > 2 {
+        return Err(MoneyParseError::TooManyDecimals);
+    }
+
+    // The digits were already checked, so this can only fail because the number is too big
+    let euros: i64 = whole.parse().map_err(|_| MoneyParseError::Overflow)?;
+    // A single decimal digit means tenths of euro, so "0.5" is 50 cents and not 5
+    let cents: i64 = format!("{:0<2}", fraction)
+        .parse()
+        .map_err(|_| MoneyParseError::InvalidNumber)?;
+    // The euros may fit in an i64 while the same amount in cents doesn't
+    let total: i64 = euros
+        .checked_mul(100)
+        .and_then(|total| total.checked_add(cents))
+        .ok_or(MoneyParseError::Overflow)?;
+
+    Ok(Money(if negative { -total } else { total }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cents_add_up_exactly() {
+        let sum: Money = Money::from_euros(0.1) + Money::from_euros(0.2);
+
+        assert_eq!(sum, Money::from_euros(0.3));
+        assert_eq!(sum.to_euros(), 0.3);
+        assert_eq!(Money::from_euros(1.0) - Money::from_euros(0.25), Money(75));
+    }
+
+    #[test]
+    fn from_euros_rounds_to_the_nearest_cent() {
+        assert_eq!(Money::from_euros(1.234), Money(123));
+        assert_eq!(Money::from_euros(1.235), Money(124));
+        assert_eq!(Money::from_euros(-0.5), Money(-50));
+    }
+
+    #[test]
+    fn split_evenly() {
+        assert_eq!(Money(300).split(3), vec![Money(100); 3]);
+    }
+
+    #[test]
+    fn split_gives_the_remainder_to_the_first_parts() {
+        let parts: Vec<Money> = Money(100).split(3);
+
+        assert_eq!(parts, vec![Money(34), Money(33), Money(33)]);
+        assert_eq!(parts.into_iter().fold(Money(0), |total, part| total + part), Money(100));
+        assert_eq!(Money(-100).split(3), vec![Money(-34), Money(-33), Money(-33)]);
+    }
+
+    #[test]
+    fn split_among_nobody_is_empty() {
+        assert!(Money(100).split(0).is_empty());
+    }
+
+    #[test]
+    fn display_formats_euros_and_cents() {
+        assert_eq!(Money(1234).to_string(), "€12.34");
+        assert_eq!(Money(5).to_string(), "€0.05");
+        assert_eq!(Money(0).to_string(), "€0.00");
+        assert_eq!(Money(-150).to_string(), "-€1.50");
+    }
+
+    #[test]
+    fn parse_accepts_the_supported_formats() {
+        assert_eq!(parse_money("12.34"), Ok(Money(1234)));
+        assert_eq!(parse_money("€12.34"), Ok(Money(1234)));
+        assert_eq!(parse_money("12"), Ok(Money(1200)));
+        assert_eq!(parse_money("0.5"), Ok(Money(50)));
+        assert_eq!(parse_money(" -€1.50 "), Ok(Money(-150)));
+    }
+
+    #[test]
+    fn parse_reads_back_what_display_writes() {
+        for cents in [0, 5, 1234, -150] {
+            assert_eq!(parse_money(&Money(cents).to_string()), Ok(Money(cents)));
+        }
+    }
+
+    #[test]
+    fn parse_rejects_invalid_amounts() {
+        assert_eq!(parse_money(""), Err(MoneyParseError::Empty));
+        assert_eq!(parse_money("€"), Err(MoneyParseError::Empty));
+        assert_eq!(parse_money("12.345"), Err(MoneyParseError::TooManyDecimals));
+        assert_eq!(parse_money("twelve"), Err(MoneyParseError::InvalidNumber));
+        assert_eq!(parse_money("1.2.3"), Err(MoneyParseError::InvalidNumber));
+        assert_eq!(parse_money("12."), Err(MoneyParseError::InvalidNumber));
+    }
+
+    #[test]
+    fn parse_rejects_amounts_too_big_for_cents() {
+        assert_eq!(parse_money("100000000000000000"), Err(MoneyParseError::Overflow));
+        assert_eq!(parse_money("9223372036854775808"), Err(MoneyParseError::Overflow));
+        assert_eq!(parse_money("-€99999999999999999999.99"), Err(MoneyParseError::Overflow));
+        assert_eq!(parse_money("92233720368547758.08"), Err(MoneyParseError::Overflow));
+        assert_eq!(parse_money("92233720368547758.07"), Ok(Money(i64::MAX)));
+    }
+}
@@ -0,0 +1,77 @@
+/// A traffic light is a real Finite State Machine: it's always in exactly one of its states, and
+/// it moves from one to the next always in the same order. An enum with a variant for each state
+/// is the most natural way of writing it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrafficLight {
+    Red,
+    Yellow,
+    Green,
+}
+
+impl TrafficLight {
+    /// The state that comes after this one. It takes `self` by value, since the old state is
+    /// replaced by the new one (and `TrafficLight` is `Copy`, so this costs nothing).
+    pub fn next(self) -> TrafficLight {
+        match self {
+            TrafficLight::Red => TrafficLight::Green,
+            TrafficLight::Green => TrafficLight::Yellow,
+            TrafficLight::Yellow => TrafficLight::Red,
+        }
+    }
+
+    /// How many seconds the light stays in this state
+    pub fn duration_secs(&self) -> u32 {
+        match self {
+            TrafficLight::Red => 30,
+            TrafficLight::Yellow => 5,
+            TrafficLight::Green => 25,
+        }
+    }
+}
+
+/// The first `steps` states of a traffic light that starts from red
+pub fn simulate(steps: usize) -> Vec<TrafficLight> {
+    let mut states: Vec<TrafficLight> = Vec::with_capacity(steps);
+    let mut light: TrafficLight = TrafficLight::Red;
+
+    for _ in 0..steps {
+        states.push(light);
+        light = light.next();
+    }
+
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_cycles_through_the_states() {
+        assert_eq!(TrafficLight::Red.next(), TrafficLight::Green);
+        assert_eq!(TrafficLight::Green.next(), TrafficLight::Yellow);
+        assert_eq!(TrafficLight::Yellow.next(), TrafficLight::Red);
+    }
+
+    #[test]
+    fn a_full_cycle_takes_one_minute() {
+        let cycle: u32 = simulate(3).iter().map(|light| light.duration_secs()).sum();
+
+        assert_eq!(cycle, 60);
+        assert_eq!(TrafficLight::Yellow.duration_secs(), 5);
+    }
+
+    #[test]
+    fn simulate_starts_from_red() {
+        assert_eq!(simulate(4), vec![TrafficLight::Red, TrafficLight::Green, TrafficLight::Yellow,
+                                     TrafficLight::Red]);
+        assert_eq!(simulate(0), vec![]);
+    }
+
+    #[test]
+    fn simulate_repeats_every_three_steps() {
+        let states: Vec<TrafficLight> = simulate(30);
+
+        assert!(states.windows(4).all(|window| window[0] == window[3]));
+    }
+}
@@ -29,6 +29,11 @@ impl Equation {
             operator: op,
         }
     }
+
+    /// Describes the type of numbers used in the equation
+    pub fn describe_numbers(&self) -> &'static str {
+        describe(&self.numbers)
+    }
 }
 
 /// Some types of numbers:
@@ -56,4 +61,34 @@ fn get_num_type(num_type: i32) -> NumberType {
         3 => NumberType::Float,
         _ => NumberType::Complex
     }
-}
\ No newline at end of file
+}
+
+/// Returns a sentence describing a [NumberType]
+fn describe(nt: &NumberType) -> &'static str {
+    match nt {
+        NumberType::Integer => "A whole number with no fractional part",
+        NumberType::Rational => "A number that can be written as a fraction of two integers",
+        NumberType::Float => "An approximation of a real number, stored with a floating point",
+        NumberType::Complex => "A number with both a real and an imaginary part",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn descriptions_are_non_empty_and_distinct() {
+        let descriptions: Vec<&str> = (1..=4).map(|n| describe(&get_num_type(n))).collect();
+        let unique: HashSet<&str> = descriptions.iter().copied().collect();
+
+        assert!(descriptions.iter().all(|description| !description.is_empty()));
+        assert_eq!(unique.len(), 4);
+    }
+
+    #[test]
+    fn integer_description() {
+        assert_eq!(describe(&NumberType::Integer), "A whole number with no fractional part");
+    }
+}
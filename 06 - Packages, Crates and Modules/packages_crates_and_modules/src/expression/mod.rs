@@ -0,0 +1,293 @@
+/// A module that reads arithmetic expressions such as `3 + 4 * (2 - 1)`. The first step is the
+/// **tokenization**: the text is split into tokens, which are the smallest meaningful pieces of the
+/// expression (numbers, operators and parentheses).
+pub fn expressions() {
+    {
+        for input in ["3 + 4 * (2 - 1)", "2.5*(1-0.5)", "3 $ 4"] {
+            println!("{:?} -> {:?}", input, tokenize(input));
+        }
+    }
+
+    /* A list of valid tokens is not necessarily a valid expression: for instance `(3 + 4` has a
+     * parenthesis that is never closed. validate_tokens() checks for these mistakes:
+     */
+
+    {
+        for input in ["3 + 4 * (2 - 1)", "(3 + 4", "3 + * 4", "* 3"] {
+            let result = tokenize(input).and_then(|tokens| validate_tokens(&tokens));
+            println!("{:?} is valid? {:?}", input, result);
+        }
+    }
+
+    /* Evaluating a whole expression takes a parser, but the simplest case, a single operation
+     * like `3 + 4`, can already be done with an enum and a `match`:
+     */
+
+    {
+        for input in ["3 + 4", "10 / 4", "2.5 * -2", "1 / 0", "3 +", "3 % 4"] {
+            println!("{:?} = {:?}", input, eval_expression(input));
+        }
+    }
+}
+
+/// A single operation between two numbers. Each variant carries its own operands.
+#[derive(Debug, PartialEq)]
+pub enum Operation {
+    Add(f64, f64),
+    Sub(f64, f64),
+    Mul(f64, f64),
+    Div(f64, f64),
+}
+
+/// The errors of [`eval_expression`]
+#[derive(Debug, PartialEq)]
+pub enum CalcError {
+    /// The input is not in the form `number operator number`
+    Parse,
+    /// The input is a division by 0
+    DivByZero,
+}
+
+impl Operation {
+    /// Reads an operation written as `number operator number`, with the three parts separated by
+    /// whitespaces
+    pub fn parse(input: &str) -> Result<Operation, CalcError> {
+        let parts: Vec<&str> = input.split_whitespace().collect();
+        let [left, operator, right] = parts[..] else {
+            return Err(CalcError::Parse);
+        };
+
+        let left: f64 = left.parse().map_err(|_| CalcError::Parse)?;
+        let right: f64 = right.parse().map_err(|_| CalcError::Parse)?;
+
+        match operator {
+            "+" => Ok(Operation::Add(left, right)),
+            "-" => Ok(Operation::Sub(left, right)),
+            "*" => Ok(Operation::Mul(left, right)),
+            "/" => Ok(Operation::Div(left, right)),
+            _ => Err(CalcError::Parse),
+        }
+    }
+
+    pub fn evaluate(&self) -> Result<f64, CalcError> {
+        match *self {
+            Operation::Add(left, right) => Ok(left + right),
+            Operation::Sub(left, right) => Ok(left - right),
+            Operation::Mul(left, right) => Ok(left * right),
+            // An f64 division by 0 would give an infinity (or NaN), but we'd rather say so
+            Operation::Div(_, 0.0) => Err(CalcError::DivByZero),
+            Operation::Div(left, right) => Ok(left / right),
+        }
+    }
+}
+
+/// Evaluates a single operation such as `"3 + 4"`. The operators are `+`, `-`, `*` and `/`, and
+/// the numbers and the operator must be separated by whitespaces.
+pub fn eval_expression(input: &str) -> Result<f64, CalcError> {
+    Operation::parse(input)?.evaluate()
+}
+
+/// A piece of an arithmetic expression
+#[derive(Debug, PartialEq)]
+pub enum Token {
+    Number(f64),
+    Op(char),
+    LParen,
+    RParen,
+}
+
+/// The errors that may occur while reading an expression
+#[derive(Debug, PartialEq)]
+pub enum TokenError {
+    /// A character that can't be part of an expression, with its position
+    UnexpectedChar(char, usize),
+    /// A sequence of digits and dots that is not a valid number, such as `1.2.3`
+    InvalidNumber(String),
+    /// A parenthesis is closed without being opened, or opened and never closed
+    UnbalancedParens,
+    /// Two operators in a row, with the index of the second one
+    AdjacentOperators(usize),
+    /// An operator at the start or at the end of the expression (or of a parenthesis), with its
+    /// index
+    MisplacedOperator(usize),
+}
+
+/// Splits `input` into tokens. The whitespaces are skipped, and the numbers can have decimals.
+pub fn tokenize(input: &str) -> Result<Vec<Token>, TokenError> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => continue,
+            '+' | '-' | '*' | '/' => tokens.push(Token::Op(c)),
+            '(' => tokens.push(Token::LParen),
+            ')' => tokens.push(Token::RParen),
+            '0'..='9' | '.' => {
+                // A number goes on as long as we find digits or dots
+                let mut number: String = String::from(c);
+                while let Some(&(_, next)) = chars.peek() {
+                    if !next.is_ascii_digit() && next != '.' {
+                        break;
+                    }
+                    number.push(next);
+                    chars.next();
+                }
+
+                match number.parse::<f64>() {
+                    Ok(value) => tokens.push(Token::Number(value)),
+                    Err(_) => return Err(TokenError::InvalidNumber(number)),
+                }
+            }
+            _ => return Err(TokenError::UnexpectedChar(c, position)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Checks that the parentheses are balanced, and that each operator is between two operands
+pub fn validate_tokens(tokens: &[Token]) -> Result<(), TokenError> {
+    let mut depth: usize = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        let previous: Option<&Token> = if i > 0 { tokens.get(i - 1) } else { None };
+
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => {
+                if depth == 0 {
+                    return Err(TokenError::UnbalancedParens);
+                }
+                depth -= 1;
+
+                if let Some(Token::Op(_)) = previous {
+                    return Err(TokenError::MisplacedOperator(i - 1));
+                }
+            }
+            Token::Op(_) => match previous {
+                Some(Token::Op(_)) => return Err(TokenError::AdjacentOperators(i)),
+                None | Some(Token::LParen) => return Err(TokenError::MisplacedOperator(i)),
+                _ => (),
+            },
+            Token::Number(_) => (),
+        }
+    }
+
+    if depth != 0 {
+        return Err(TokenError::UnbalancedParens);
+    }
+    if let Some(Token::Op(_)) = tokens.last() {
+        return Err(TokenError::MisplacedOperator(tokens.len() - 1));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_an_expression() {
+        assert_eq!(tokenize("3 + 4 * (2 - 1)"), Ok(vec![
+            Token::Number(3.0),
+            Token::Op('+'),
+            Token::Number(4.0),
+            Token::Op('*'),
+            Token::LParen,
+            Token::Number(2.0),
+            Token::Op('-'),
+            Token::Number(1.0),
+            Token::RParen,
+        ]));
+    }
+
+    #[test]
+    fn tokenize_decimals_without_spaces() {
+        assert_eq!(tokenize("2.5*.5"),
+                   Ok(vec![Token::Number(2.5), Token::Op('*'), Token::Number(0.5)]));
+    }
+
+    #[test]
+    fn tokenize_skips_every_whitespace() {
+        assert_eq!(tokenize("3\r\n+\t4"), tokenize("3 + 4"));
+        assert_eq!(tokenize(" \u{a0}\n"), Ok(vec![]));
+    }
+
+    #[test]
+    fn tokenize_rejects_unexpected_characters() {
+        assert_eq!(tokenize("3 $ 4"), Err(TokenError::UnexpectedChar('$', 2)));
+        assert_eq!(tokenize("1.2.3"), Err(TokenError::InvalidNumber(String::from("1.2.3"))));
+    }
+
+    /// Tokenizes `input`, which must contain only valid tokens, and validates it
+    fn validate(input: &str) -> Result<(), TokenError> {
+        validate_tokens(&tokenize(input).unwrap())
+    }
+
+    #[test]
+    fn validate_tokens_of_a_valid_expression() {
+        assert_eq!(validate("3 + 4 * (2 - 1)"), Ok(()));
+        assert_eq!(validate("((1))"), Ok(()));
+        assert_eq!(validate_tokens(&[]), Ok(()));
+    }
+
+    #[test]
+    fn validate_tokens_with_unbalanced_parens() {
+        assert_eq!(validate("(3 + 4"), Err(TokenError::UnbalancedParens));
+        assert_eq!(validate("3 + 4)"), Err(TokenError::UnbalancedParens));
+        assert_eq!(validate(")("), Err(TokenError::UnbalancedParens));
+    }
+
+    #[test]
+    fn validate_tokens_with_adjacent_operators() {
+        assert_eq!(validate("3 + * 4"), Err(TokenError::AdjacentOperators(2)));
+    }
+
+    #[test]
+    fn validate_tokens_with_misplaced_operators() {
+        assert_eq!(validate("* 3"), Err(TokenError::MisplacedOperator(0)));
+        assert_eq!(validate("3 +"), Err(TokenError::MisplacedOperator(1)));
+        assert_eq!(validate("(+ 3)"), Err(TokenError::MisplacedOperator(1)));
+        assert_eq!(validate("(3 -)"), Err(TokenError::MisplacedOperator(2)));
+    }
+
+    #[test]
+    fn parse_each_operator() {
+        assert_eq!(Operation::parse("3 + 4"), Ok(Operation::Add(3.0, 4.0)));
+        assert_eq!(Operation::parse("3 - 4"), Ok(Operation::Sub(3.0, 4.0)));
+        assert_eq!(Operation::parse("2.5 * -2"), Ok(Operation::Mul(2.5, -2.0)));
+        assert_eq!(Operation::parse("  10\t/ 4 "), Ok(Operation::Div(10.0, 4.0)));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_operations() {
+        for input in ["", "3 +", "3 + 4 + 5", "3+4", "3 % 4", "three + 4", "3 + four"] {
+            assert_eq!(Operation::parse(input), Err(CalcError::Parse), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn evaluate_each_operation() {
+        assert_eq!(Operation::Add(3.0, 4.0).evaluate(), Ok(7.0));
+        assert_eq!(Operation::Sub(3.0, 4.0).evaluate(), Ok(-1.0));
+        assert_eq!(Operation::Mul(2.5, -2.0).evaluate(), Ok(-5.0));
+        assert_eq!(Operation::Div(10.0, 4.0).evaluate(), Ok(2.5));
+    }
+
+    #[test]
+    fn evaluate_a_division_by_zero() {
+        assert_eq!(Operation::Div(1.0, 0.0).evaluate(), Err(CalcError::DivByZero));
+        assert_eq!(Operation::Div(1.0, -0.0).evaluate(), Err(CalcError::DivByZero));
+        assert_eq!(Operation::Div(0.0, 0.0).evaluate(), Err(CalcError::DivByZero));
+    }
+
+    #[test]
+    fn eval_expression_parses_and_evaluates() {
+        assert_eq!(eval_expression("3 + 4"), Ok(7.0));
+        assert_eq!(eval_expression("10 / 4"), Ok(2.5));
+        assert_eq!(eval_expression("1 / 0"), Err(CalcError::DivByZero));
+        assert_eq!(eval_expression("3 +"), Err(CalcError::Parse));
+    }
+}
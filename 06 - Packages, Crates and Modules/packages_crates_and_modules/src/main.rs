@@ -3,6 +3,8 @@ extern crate core;
 pub mod packages;
 pub mod a_module;
 pub mod another_module;
+pub mod expression;
+pub mod math;
 
 /// As one proceeds in writing a project, it may be normal to want to split the code into multiple
 /// files, may it be for clearance, for keeping order, or for the need of modularity. Rust allows
@@ -22,6 +24,14 @@ fn main() {
     {
         packages::modules_func();
     }
+
+    {
+        math::math();
+    }
+
+    {
+        expression::expressions();
+    }
 }
 
 
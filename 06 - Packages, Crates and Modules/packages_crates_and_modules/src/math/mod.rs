@@ -0,0 +1,383 @@
+pub mod numerals;
+pub mod primes;
+pub mod rational;
+
+/// A module with some numeric helpers. Like `a_module` and `another_module`, it lives in a folder
+/// with a `mod.rs` file, so that it can be split into submodules as it grows.
+pub fn math() {
+    {
+        let sum: f64 = 0.1 + 0.2;
+
+        println!("0.1 + 0.2 == 0.3? {} | approx_eq? {}", sum == 0.3, approx_eq(sum, 0.3, 1e-9));
+        println!("NaN is never approximately equal, not even to itself: {}",
+                 approx_eq(f64::NAN, f64::NAN, 1.0));
+    }
+
+    {
+        println!("{} | {} | {} | {}", round_to(12.3456, 2), round_to(2.5, 0), round_to(1.005_1, 2),
+                 round_to(-7.456, 1));
+    }
+
+    // The submodules can be reached through the path of their parent, like `primes::primes_up_to`
+
+    {
+        println!("Primes up to 50: {:?}", primes::primes_up_to(50));
+        println!("360 = {:?} | 97 = {:?}", primes::prime_factors(360), primes::prime_factors(97));
+
+        let candidates: [u64; 6] = [0, 1, 2, 91, 97, 7919];
+        for n in candidates {
+            print!("{} is prime? {} | ", n, primes::is_prime(n));
+        }
+        println!();
+    }
+
+    {
+        for n in [4, 1994, 2024, 0, 4000] {
+            println!("{} in Roman numerals is {:?}", n, numerals::to_roman(n));
+        }
+        println!("MCMXCIV = {:?} | IIII = {:?}", numerals::from_roman("MCMXCIV"),
+                 numerals::from_roman("IIII"));
+    }
+
+    {
+        println!("255 in base 2: {:?} | in base 16: {:?} | in base 36: {:?}",
+                 numerals::to_base(255, 2), numerals::to_base(255, 16), numerals::to_base(255, 36));
+        println!("\"ff\" in base 16: {:?} | \"zz\" in base 36: {:?} | \"12\" in base 2: {:?} | \
+                  base 1: {:?}", numerals::from_base("ff", 16), numerals::from_base("zz", 36),
+                 numerals::from_base("12", 2), numerals::to_base(10, 1));
+    }
+
+    {
+        let triangulars: Vec<u64> = (1..=8).map(triangular).collect();
+        println!("The first triangular numbers: {:?}", triangulars);
+
+        for n in [0, 1, 16, 18, 1 << 52] {
+            print!("{} is a perfect square? {} | ", n, is_perfect_square(n));
+        }
+        println!();
+
+        println!("isqrt(15) = {} | isqrt(16) = {} | isqrt(u64::MAX) = {}", isqrt(15), isqrt(16),
+                 isqrt(u64::MAX));
+    }
+
+    // A Rational keeps a fraction exact, and we can find the one closest to a decimal number
+
+    {
+        println!("2/4 = {} | 3/-6 = {}", rational::Rational::new(2, 4),
+                 rational::Rational::new(3, -6));
+
+        for (value, max_denominator) in [(0.5, 100), (1.0 / 3.0, 100), (7.0, 10), (-0.75, 10),
+                                         (std::f64::consts::PI, 1000)] {
+            println!("{} is about {}", value,
+                     rational::decimal_to_fraction(value, max_denominator));
+        }
+
+        for (numerator, denominator) in [(3, 2), (10, 2), (2, 3), (-4, 3)] {
+            let fraction: rational::Rational = rational::Rational::new(numerator, denominator);
+            println!("{} as a mixed number is {}", fraction, fraction.to_mixed_string());
+        }
+
+        let half: rational::Rational = rational::Rational::new(1, 2);
+        println!("1/2 == 2/4? {} | 1/3 < 1/2? {}", half == rational::Rational::new(2, 4),
+                 rational::Rational::new(1, 3) < half);
+
+        let mut fractions: Vec<rational::Rational> = [(3, 4), (-1, 2), (2, 3), (1, 8)]
+            .into_iter()
+            .map(|(numerator, denominator)| rational::Rational::new(numerator, denominator))
+            .collect();
+        fractions.sort();
+        let sorted: Vec<String> = fractions.iter().map(|f| f.to_string()).collect();
+        println!("Sorted fractions: {}", sorted.join(" < "));
+
+        let third: rational::Rational = rational::Rational::new(1, 3);
+        println!("1/2 + 1/3 = {} | 1/2 - 1/3 = {} | 1/2 × 1/3 = {} | 1/2 ÷ 1/3 = {:?}",
+                 half.add(&third), half.sub(&third), half.mul(&third), half.div(&third));
+        println!("1/2 ÷ 0 = {:?}", half.div(&rational::Rational::new(0, 5)));
+
+        println!("415/93 = {:?} | 6/1 = {:?} | -7/3 = {:?}", rational::continued_fraction(415, 93),
+                 rational::continued_fraction(6, 1), rational::continued_fraction(-7, 3));
+    }
+
+    // The Leibniz series gets closer to pi with every term, but very slowly
+
+    {
+        for terms in [0, 1, 10, 1_000, 100_000] {
+            println!("pi with {} terms: {}", terms, approximate_pi(terms));
+        }
+    }
+
+    // Newton's method, instead, doubles the number of correct digits at every step
+
+    {
+        for x in [2.0, 16.0, 1e10, -4.0] {
+            println!("sqrt({}) with Newton's method: {:?}", x, newton_sqrt(x, 1e-12));
+        }
+    }
+}
+
+/// Floating point numbers carry small errors, so comparing them with `==` is not reliable. This
+/// function says whether `a` and `b` differ by at most `epsilon`. Two infinities with the same sign
+/// are equal, while `NaN` is never equal to anything.
+pub fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    if a.is_nan() || b.is_nan() {
+        return false;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return a == b;
+    }
+
+    (a - b).abs() <= epsilon
+}
+
+/// Rounds `value` to the given number of decimal places. Halves are rounded away from zero, so
+/// `2.5` becomes `3.0` and `-2.5` becomes `-3.0`. Keep in mind that the result is still an `f64`,
+/// so it may not be exactly the decimal number that we would write on paper.
+///
+/// An `f64` has less than 20 significant digits, so with hundreds of decimals there is nothing
+/// left to round: there `10^decimals` (or `value` times it) becomes infinite, and we return
+/// `value` as it is instead of a `NaN` or an infinity.
+pub fn round_to(value: f64, decimals: u32) -> f64 {
+    let factor: f64 = 10_f64.powi(decimals.try_into().unwrap_or(i32::MAX));
+    let scaled: f64 = value * factor;
+
+    if !factor.is_finite() || !scaled.is_finite() {
+        return value;
+    }
+
+    scaled.round() / factor
+}
+
+/// The `n`-th triangular number, that is the sum `1 + 2 + ... + n`, which is `n * (n + 1) / 2`.
+/// One of `n` and `n + 1` is always even, so we halve that one before multiplying: this way the
+/// product overflows only if the result itself doesn't fit in a `u64`.
+pub fn triangular(n: u64) -> u64 {
+    let (even, odd): (u64, u64) = if n.is_multiple_of(2) { (n, n + 1) } else { (n + 1, n) };
+
+    even / 2 * odd
+}
+
+/// Whether `n` is the square of a whole number
+pub fn is_perfect_square(n: u64) -> bool {
+    let root: u64 = isqrt(n);
+
+    root * root == n
+}
+
+/// The square root of `n`, rounded down. An `f64` can't represent every `u64`, so
+/// `(n as f64).sqrt()` may be off by one for big numbers: here we use only integer math instead.
+///
+/// It's a binary search on the answer: the root is in `0..=u32::MAX`, since `u32::MAX` squared is
+/// the biggest square that fits in a `u64`, and we look for the biggest `r` with `r * r <= n`.
+pub fn isqrt(n: u64) -> u64 {
+    let mut low: u64 = 0;
+    let mut high: u64 = u32::MAX as u64;
+
+    while low < high {
+        // Rounding up, otherwise with `high = low + 1` the middle would be `low` forever
+        let middle: u64 = low + (high - low).div_ceil(2);
+
+        if middle * middle <= n {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    low
+}
+
+/// Approximates pi with the first `terms` terms of the Leibniz series,
+/// `pi = 4 * (1 - 1/3 + 1/5 - 1/7 + ...)`. After `n` terms the error is smaller than `1 / n`, so
+/// each new correct digit needs ten times the terms of the previous one. With 0 terms we get 0.
+pub fn approximate_pi(terms: usize) -> f64 {
+    // `fold` from 0.0 and not `sum`, since the sum of no f64 is -0.0
+    let sum: f64 = (0..terms).fold(0.0, |sum, k| {
+        let sign: f64 = if k.is_multiple_of(2) { 1.0 } else { -1.0 };
+        sum + sign / (2 * k + 1) as f64
+    });
+
+    4.0 * sum
+}
+
+/// Computes the square root of `x` with Newton's method, returning it together with the number of
+/// iterations done. Each iteration replaces the guess `g` with the average of `g` and `x / g`,
+/// and we stop when two guesses in a row differ by less than `tolerance`.
+///
+/// Negative numbers (and `NaN`) have no real square root, so they give `None`, while 0 and the
+/// positive infinity are their own roots and need no iterations. A `tolerance` too small may never
+/// be reached because of the rounding of `f64`, so we stop after 100 iterations anyway.
+pub fn newton_sqrt(x: f64, tolerance: f64) -> Option<(f64, u32)> {
+    if x.is_nan() || x < 0.0 {
+        return None;
+    }
+    // With an infinite guess `x / guess` would be NaN
+    if x == 0.0 || x.is_infinite() {
+        return Some((x, 0));
+    }
+
+    // The root of a number bigger than 1 is smaller than the number itself, so we start from there
+    let mut guess: f64 = x.max(1.0);
+
+    for iteration in 1..=100 {
+        let next: f64 = (guess + x / guess) / 2.0;
+
+        if (next - guess).abs() < tolerance {
+            return Some((next, iteration));
+        }
+        guess = next;
+    }
+
+    Some((guess, 100))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approx_eq_within_epsilon() {
+        assert!(approx_eq(0.1 + 0.2, 0.3, 1e-9));
+        assert!(approx_eq(1.0, 1.05, 0.1));
+        assert!(approx_eq(-2.0, -2.0, 0.0));
+    }
+
+    #[test]
+    fn approx_eq_outside_epsilon() {
+        assert!(!approx_eq(1.0, 1.2, 0.1));
+        assert!(!approx_eq(0.1 + 0.2, 0.3, 0.0));
+    }
+
+    #[test]
+    fn approx_eq_with_infinities_and_nan() {
+        assert!(approx_eq(f64::INFINITY, f64::INFINITY, 1e-9));
+        assert!(!approx_eq(f64::INFINITY, f64::NEG_INFINITY, 1e-9));
+        assert!(!approx_eq(f64::INFINITY, f64::MAX, f64::INFINITY));
+        assert!(!approx_eq(f64::NAN, f64::NAN, 1.0));
+        assert!(!approx_eq(f64::NAN, 0.0, f64::INFINITY));
+    }
+
+    #[test]
+    fn round_to_some_decimals() {
+        assert_eq!(round_to(1.23456, 2), 1.23);
+        assert_eq!(round_to(12.3456, 2), 12.35);
+        assert_eq!(round_to(2.675_1, 2), 2.68);
+    }
+
+    #[test]
+    fn round_to_a_whole_number() {
+        assert_eq!(round_to(2.5, 0), 3.0);
+        assert_eq!(round_to(2.4, 0), 2.0);
+    }
+
+    #[test]
+    fn round_to_negative_values() {
+        assert_eq!(round_to(-7.456, 1), -7.5);
+        assert_eq!(round_to(-2.5, 0), -3.0);
+    }
+
+    #[test]
+    fn round_to_too_many_decimals_keeps_the_value() {
+        assert_eq!(round_to(1.5, 400), 1.5);
+        assert_eq!(round_to(1e300, 100), 1e300);
+        assert_eq!(round_to(0.125, u32::MAX), 0.125);
+    }
+
+    #[test]
+    fn triangular_numbers() {
+        let first: Vec<u64> = (0..=8).map(triangular).collect();
+
+        assert_eq!(first, vec![0, 1, 3, 6, 10, 15, 21, 28, 36]);
+    }
+
+    #[test]
+    fn triangular_of_a_big_n_does_not_overflow_too_early() {
+        // n * (n + 1) would overflow, but the result itself fits in a u64
+        assert_eq!(triangular(u32::MAX as u64), 9_223_372_034_707_292_160);
+    }
+
+    #[test]
+    fn is_perfect_square_detection() {
+        for n in [0, 1, 16, 1 << 52, (u32::MAX as u64).pow(2)] {
+            assert!(is_perfect_square(n), "{} is a perfect square", n);
+        }
+        for n in [2, 15, 18, u64::MAX] {
+            assert!(!is_perfect_square(n), "{} is not a perfect square", n);
+        }
+    }
+
+    #[test]
+    fn isqrt_of_perfect_squares() {
+        for root in [0, 1, 2, 12, 1 << 20] {
+            assert_eq!(isqrt(root * root), root);
+        }
+    }
+
+    #[test]
+    fn isqrt_rounds_down() {
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(17), 4);
+        assert_eq!(isqrt(2), 1);
+    }
+
+    #[test]
+    fn isqrt_near_u64_max() {
+        // The f64 square root of u64::MAX is rounded up to 2^32, which is one too many
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt((u32::MAX as u64).pow(2)), u32::MAX as u64);
+        assert_eq!(isqrt((u32::MAX as u64).pow(2) - 1), u32::MAX as u64 - 1);
+    }
+
+    #[test]
+    fn approximate_pi_gets_closer_with_more_terms() {
+        use std::f64::consts::PI;
+
+        assert_eq!(approximate_pi(1), 4.0);
+        assert!(approx_eq(approximate_pi(2), 8.0 / 3.0, 1e-12));
+        assert!(approx_eq(approximate_pi(1_000), PI, 1e-2));
+        assert!(approx_eq(approximate_pi(100_000), PI, 1e-4));
+        assert!((approximate_pi(1_000) - PI).abs() < (approximate_pi(10) - PI).abs());
+    }
+
+    #[test]
+    fn approximate_pi_with_no_terms_is_positive_zero() {
+        assert_eq!(approximate_pi(0), 0.0);
+        assert!(approximate_pi(0).is_sign_positive());
+    }
+
+    #[test]
+    fn newton_sqrt_of_two() {
+        let (root, iterations): (f64, u32) = newton_sqrt(2.0, 1e-12).unwrap();
+
+        assert!(approx_eq(root, std::f64::consts::SQRT_2, 1e-12));
+        assert!(iterations < 10);
+    }
+
+    #[test]
+    fn newton_sqrt_of_perfect_squares() {
+        for (x, root) in [(1.0, 1.0), (16.0, 4.0), (1e10, 1e5), (0.25, 0.5)] {
+            let (found, _) = newton_sqrt(x, 1e-12).unwrap();
+            assert!(approx_eq(found, root, 1e-9), "sqrt({}) = {}", x, found);
+        }
+    }
+
+    #[test]
+    fn newton_sqrt_without_a_real_root() {
+        assert_eq!(newton_sqrt(-4.0, 1e-12), None);
+        assert_eq!(newton_sqrt(f64::NEG_INFINITY, 1e-12), None);
+        assert_eq!(newton_sqrt(f64::NAN, 1e-12), None);
+    }
+
+    #[test]
+    fn newton_sqrt_of_zero_and_infinity() {
+        assert_eq!(newton_sqrt(0.0, 1e-12), Some((0.0, 0)));
+        assert_eq!(newton_sqrt(f64::INFINITY, 1e-12), Some((f64::INFINITY, 0)));
+    }
+
+    #[test]
+    fn newton_sqrt_stops_after_100_iterations() {
+        let (root, iterations): (f64, u32) = newton_sqrt(2.0, -1.0).unwrap();
+
+        assert_eq!(iterations, 100);
+        assert!(approx_eq(root, std::f64::consts::SQRT_2, 1e-12));
+    }
+}
@@ -0,0 +1,184 @@
+/// The Roman symbols with their values, from the biggest to the smallest. The pairs like `CM` and
+/// `IV` are the "subtractive" forms, where a smaller symbol before a bigger one is subtracted.
+const ROMAN_SYMBOLS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Writes `n` as a Roman numeral. Romans had no symbol for 0, and the standard notation only goes
+/// up to 3999 (`MMMCMXCIX`), so for any other number we return `None`.
+pub fn to_roman(mut n: u32) -> Option<String> {
+    if !(1..=3999).contains(&n) {
+        return None;
+    }
+
+    let mut result: String = String::new();
+    for (value, symbol) in ROMAN_SYMBOLS {
+        while n >= value {
+            result.push_str(symbol);
+            n -= value;
+        }
+    }
+
+    Some(result)
+}
+
+/// Reads a Roman numeral. Only numerals written in the standard form are accepted: for instance
+/// `IIII` or `IC` are rejected, since 4 and 99 are written `IV` and `XCIX`.
+pub fn from_roman(s: &str) -> Option<u32> {
+    let mut rest: &str = s;
+    let mut total: u32 = 0;
+
+    for (value, symbol) in ROMAN_SYMBOLS {
+        while let Some(after) = rest.strip_prefix(symbol) {
+            total += value;
+            rest = after;
+        }
+    }
+
+    /* If something is left, the symbols were in the wrong order. We also write the number again:
+     * if we don't get back the same string, then it wasn't in the standard form.
+     */
+    if !rest.is_empty() || to_roman(total).as_deref() != Some(s) {
+        return None;
+    }
+
+    Some(total)
+}
+
+/// The digits used for the bases from 2 to 36
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// The errors that may occur while converting a number from or to a base
+#[derive(Debug, PartialEq)]
+pub enum BaseError {
+    /// The base is not between 2 and 36
+    InvalidBase(u32),
+    /// The string contains a character that is not a digit of the base
+    InvalidDigit(char),
+    /// The string is empty
+    Empty,
+    /// The number doesn't fit in a `u64`
+    Overflow,
+}
+
+/// Writes `n` in the given base (from 2 to 36), using the digits `0-9` and then the letters `a-z`
+pub fn to_base(mut n: u64, base: u32) -> Result<String, BaseError> {
+    if !(2..=36).contains(&base) {
+        return Err(BaseError::InvalidBase(base));
+    }
+    if n == 0 {
+        return Ok(String::from("0"));
+    }
+
+    // The digits come out starting from the least significant one, so we reverse them at the end
+    let mut digits: Vec<char> = Vec::new();
+    while n > 0 {
+        digits.push(DIGITS[(n % base as u64) as usize] as char);
+        n /= base as u64;
+    }
+
+    Ok(digits.iter().rev().collect())
+}
+
+/// Reads a number written in the given base (from 2 to 36). Both lowercase and uppercase letters
+/// are accepted.
+pub fn from_base(s: &str, base: u32) -> Result<u64, BaseError> {
+    if !(2..=36).contains(&base) {
+        return Err(BaseError::InvalidBase(base));
+    }
+    if s.is_empty() {
+        return Err(BaseError::Empty);
+    }
+
+    let mut result: u64 = 0;
+    for c in s.chars() {
+        let digit: u32 = c.to_digit(base).ok_or(BaseError::InvalidDigit(c))?;
+
+        result = result
+            .checked_mul(base as u64)
+            .and_then(|r| r.checked_add(digit as u64))
+            .ok_or(BaseError::Overflow)?;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_roman_of_some_numbers() {
+        assert_eq!(to_roman(4).as_deref(), Some("IV"));
+        assert_eq!(to_roman(1994).as_deref(), Some("MCMXCIV"));
+        assert_eq!(to_roman(3999).as_deref(), Some("MMMCMXCIX"));
+    }
+
+    #[test]
+    fn to_roman_rejects_out_of_range_numbers() {
+        assert_eq!(to_roman(0), None);
+        assert_eq!(to_roman(4000), None);
+    }
+
+    #[test]
+    fn roman_numerals_round_trip() {
+        for n in 1..=3999 {
+            let numeral: String = to_roman(n).unwrap();
+            assert_eq!(from_roman(&numeral), Some(n));
+        }
+    }
+
+    #[test]
+    fn from_roman_rejects_non_standard_numerals() {
+        for numeral in ["", "IIII", "IC", "VX", "MMMM", "ABC", "iv"] {
+            assert_eq!(from_roman(numeral), None, "{:?} is not valid", numeral);
+        }
+    }
+
+    #[test]
+    fn to_base_binary_hex_and_base_36() {
+        assert_eq!(to_base(255, 2), Ok(String::from("11111111")));
+        assert_eq!(to_base(255, 16), Ok(String::from("ff")));
+        assert_eq!(to_base(1295, 36), Ok(String::from("zz")));
+        assert_eq!(to_base(0, 7), Ok(String::from("0")));
+    }
+
+    #[test]
+    fn from_base_binary_hex_and_base_36() {
+        assert_eq!(from_base("11111111", 2), Ok(255));
+        assert_eq!(from_base("ff", 16), Ok(255));
+        assert_eq!(from_base("FF", 16), Ok(255));
+        assert_eq!(from_base("zz", 36), Ok(1295));
+    }
+
+    #[test]
+    fn bases_round_trip() {
+        for base in 2..=36 {
+            for n in [0, 1, 35, 36, 1_000_000, u64::MAX] {
+                assert_eq!(from_base(&to_base(n, base).unwrap(), base), Ok(n));
+            }
+        }
+    }
+
+    #[test]
+    fn base_conversions_reject_invalid_input() {
+        assert_eq!(to_base(10, 1), Err(BaseError::InvalidBase(1)));
+        assert_eq!(to_base(10, 37), Err(BaseError::InvalidBase(37)));
+        assert_eq!(from_base("10", 0), Err(BaseError::InvalidBase(0)));
+        assert_eq!(from_base("12", 2), Err(BaseError::InvalidDigit('2')));
+        assert_eq!(from_base("", 10), Err(BaseError::Empty));
+        assert_eq!(from_base("10000000000000000", 16), Err(BaseError::Overflow));
+    }
+}
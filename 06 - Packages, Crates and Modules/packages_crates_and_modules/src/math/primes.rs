@@ -0,0 +1,141 @@
+/// Returns all the prime numbers up to `n` (included), using the **sieve of Eratosthenes**: we
+/// start by considering every number as prime, then for each prime we cross out all of its
+/// multiples. The numbers that are never crossed out are the primes.
+pub fn primes_up_to(n: u32) -> Vec<u32> {
+    if n < 2 {
+        return Vec::new();
+    }
+
+    let n: usize = n as usize;
+    let mut is_prime: Vec<bool> = vec![true; n + 1];
+    is_prime[0] = false;
+    is_prime[1] = false;
+
+    let mut i: usize = 2;
+    while i * i <= n {
+        if is_prime[i] {
+            // The multiples smaller than i * i were already crossed out by smaller primes
+            for multiple in (i * i..=n).step_by(i) {
+                is_prime[multiple] = false;
+            }
+        }
+        i += 1;
+    }
+
+    (2..=n).filter(|&k| is_prime[k]).map(|k| k as u32).collect()
+}
+
+/// Says whether `n` is prime, by trying to divide it by every number up to its square root (if `n`
+/// had a divisor bigger than its square root, it would also have one smaller than it)
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    let mut divisor: u64 = 2;
+    // Like `divisor * divisor <= n`, but without overflowing when `divisor` gets big
+    while divisor <= n / divisor {
+        if n.is_multiple_of(divisor) {
+            return false;
+        }
+        divisor += 1;
+    }
+
+    true
+}
+
+/// Returns the prime factors of `n` in ascending order, repeating each factor as many times as it
+/// divides `n` (so 12 gives `[2, 2, 3]`). 1 has no prime factors, and neither does 0.
+pub fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors: Vec<u64> = Vec::new();
+    if n < 2 {
+        return factors;
+    }
+
+    let mut divisor: u64 = 2;
+    // Like `divisor * divisor <= n`, but without overflowing when `divisor` gets big
+    while divisor <= n / divisor {
+        while n.is_multiple_of(divisor) {
+            factors.push(divisor);
+            n /= divisor;
+        }
+        divisor += 1;
+    }
+
+    // Whatever remains is a prime bigger than the square root of the original number
+    if n > 1 {
+        factors.push(n);
+    }
+
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primes_up_to_ten() {
+        assert_eq!(primes_up_to(10), vec![2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn primes_up_to_includes_n() {
+        assert_eq!(primes_up_to(2), vec![2]);
+        assert_eq!(primes_up_to(13), vec![2, 3, 5, 7, 11, 13]);
+        assert_eq!(primes_up_to(100).len(), 25);
+    }
+
+    #[test]
+    fn primes_up_to_zero_and_one_are_empty() {
+        assert!(primes_up_to(0).is_empty());
+        assert!(primes_up_to(1).is_empty());
+    }
+
+    #[test]
+    fn prime_factors_of_a_prime() {
+        assert_eq!(prime_factors(97), vec![97]);
+    }
+
+    #[test]
+    fn prime_factors_of_a_composite() {
+        assert_eq!(prime_factors(12), vec![2, 2, 3]);
+        assert_eq!(prime_factors(360), vec![2, 2, 2, 3, 3, 5]);
+        assert_eq!(prime_factors(u64::MAX), vec![3, 5, 17, 257, 641, 65537, 6700417]);
+    }
+
+    #[test]
+    fn prime_factors_of_a_power_of_two() {
+        assert_eq!(prime_factors(1024), vec![2; 10]);
+        assert_eq!(prime_factors(1 << 63), vec![2; 63]);
+    }
+
+    #[test]
+    fn prime_factors_of_one_and_zero() {
+        assert!(prime_factors(1).is_empty());
+        assert!(prime_factors(0).is_empty());
+    }
+
+    #[test]
+    fn is_prime_edge_cases() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+        assert!(is_prime(3));
+        assert!(!is_prime(4));
+    }
+
+    #[test]
+    fn is_prime_of_some_primes() {
+        for n in [5, 13, 97, 7919, 4_294_967_291] {
+            assert!(is_prime(n), "{} is prime", n);
+        }
+    }
+
+    #[test]
+    fn is_prime_of_some_composites() {
+        for n in [9, 91, 7917, 4_294_967_297, u64::MAX] {
+            assert!(!is_prime(n), "{} is not prime", n);
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A fraction `numerator / denominator` of two integers. Unlike an `f64`, it represents numbers
+/// like 1/3 exactly.
+///
+/// The fraction is always kept reduced, and the sign is kept in the numerator, so the denominator
+/// is always positive: this way each number has exactly one representation (2/4 and -1/-2 both
+/// become 1/2).
+#[derive(Debug, Clone, Copy)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    /// Builds the fraction and reduces it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is 0, exactly like dividing an integer by 0 does.
+    pub fn new(numerator: i64, denominator: i64) -> Rational {
+        Rational::reduced(numerator as i128, denominator as i128)
+    }
+
+    /// Reduces a fraction whose terms may be bigger than an `i64`, as it happens in the middle of
+    /// the arithmetic operations. It panics if the reduced fraction doesn't fit in a Rational, as
+    /// an integer overflow does in debug builds.
+    fn reduced(numerator: i128, denominator: i128) -> Rational {
+        assert!(denominator != 0, "the denominator of a Rational can't be 0");
+
+        let divisor: i128 = gcd(numerator, denominator);
+        let sign: i128 = denominator.signum();
+
+        Rational {
+            numerator: i64::try_from(sign * numerator / divisor).expect("Rational overflow"),
+            denominator: i64::try_from(sign * denominator / divisor).expect("Rational overflow"),
+        }
+    }
+
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// Writes the fraction as a mixed number, with the whole part apart: 3/2 becomes `"1 1/2"`,
+    /// 10/2 becomes `"5"` and -4/3 becomes `"-1 1/3"`. A fraction smaller than 1 (in absolute
+    /// value) has no whole part, so it's written as it is.
+    pub fn to_mixed_string(&self) -> String {
+        // Integer division truncates towards 0, so the whole part carries the sign
+        let whole: i64 = self.numerator / self.denominator;
+        let remainder: i64 = (self.numerator % self.denominator).abs();
+
+        if remainder == 0 {
+            whole.to_string()
+        } else if whole == 0 {
+            self.to_string()
+        } else {
+            format!("{} {}/{}", whole, remainder, self.denominator)
+        }
+    }
+}
+
+/// The errors that the operations between Rationals may return
+#[derive(Debug, PartialEq)]
+pub enum RationalError {
+    /// The divisor is 0, so the result would have a denominator of 0
+    DivisionByZero,
+}
+
+/* The operations follow the rules that we learn at school: a/b + c/d = (ad + cb) / bd, and
+ * a/b ÷ c/d = a/b × d/c. The products are computed as i128, so that they can't overflow before
+ * the result is reduced.
+ */
+
+impl Rational {
+    pub fn add(&self, other: &Rational) -> Rational {
+        let (left, right) = self.cross_products(other);
+        Rational::reduced(left + right, self.denominator as i128 * other.denominator as i128)
+    }
+
+    pub fn sub(&self, other: &Rational) -> Rational {
+        let (left, right) = self.cross_products(other);
+        Rational::reduced(left - right, self.denominator as i128 * other.denominator as i128)
+    }
+
+    pub fn mul(&self, other: &Rational) -> Rational {
+        Rational::reduced(self.numerator as i128 * other.numerator as i128,
+                          self.denominator as i128 * other.denominator as i128)
+    }
+
+    /// Divides by `other`, which fails only if `other` is 0
+    pub fn div(&self, other: &Rational) -> Result<Rational, RationalError> {
+        if other.numerator == 0 {
+            return Err(RationalError::DivisionByZero);
+        }
+
+        Ok(Rational::reduced(self.numerator as i128 * other.denominator as i128,
+                             self.denominator as i128 * other.numerator as i128))
+    }
+}
+
+/* Two fractions a/b and c/d are equal when a * d == c * b, and since the denominators are always
+ * positive, a/b < c/d exactly when a * d < c * b. This way we never divide, so there is no
+ * rounding error as there would be comparing the two fractions as f64. The products of two i64 may
+ * not fit in an i64, but they always fit in an i128.
+ */
+
+impl Rational {
+    fn cross_products(&self, other: &Rational) -> (i128, i128) {
+        (self.numerator as i128 * other.denominator as i128,
+         other.numerator as i128 * self.denominator as i128)
+    }
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, other: &Rational) -> bool {
+        let (left, right) = self.cross_products(other);
+        left == right
+    }
+}
+
+impl Eq for Rational {}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        let (left, right) = self.cross_products(other);
+        left.cmp(&right)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+/// The greatest common divisor with Euclid's algorithm. It's always positive (1 when both numbers
+/// are 0), so that dividing by it doesn't change any sign.
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b): (i128, i128) = (a.abs(), b.abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a.max(1)
+}
+
+/// Finds the fraction closest to `value` whose denominator is at most `max_denominator`.
+///
+/// The algorithm is the one of continued fractions: we take the integer part of the number, then
+/// we repeat with the inverse of what's left. Each step gives a fraction (a "convergent") closer
+/// to the value than the previous one, and we stop when the next one would have a denominator too
+/// big, or when nothing is left. A `max_denominator` of 0 is treated as 1, while `NaN` and the
+/// infinities, which have no fraction at all, give 0/1.
+pub fn decimal_to_fraction(value: f64, max_denominator: u64) -> Rational {
+    if !value.is_finite() {
+        return Rational::new(0, 1);
+    }
+
+    let max_denominator: i64 = max_denominator.clamp(1, i64::MAX as u64) as i64;
+    let sign: i64 = if value < 0.0 { -1 } else { 1 };
+
+    // The last two convergents, starting from the conventional 1/0 and 0/1
+    let (mut h_previous, mut h): (i64, i64) = (0, 1);
+    let (mut k_previous, mut k): (i64, i64) = (1, 0);
+    let mut x: f64 = value.abs();
+
+    loop {
+        // The cast saturates, so a value too big for an i64 becomes i64::MAX
+        let integer: i64 = x.floor() as i64;
+
+        let next_h: Option<i64> = integer.checked_mul(h).and_then(|n| n.checked_add(h_previous));
+        let next_k: Option<i64> = integer.checked_mul(k).and_then(|n| n.checked_add(k_previous));
+
+        match (next_h, next_k) {
+            (Some(next_h), Some(next_k)) if next_k <= max_denominator => {
+                (h_previous, h) = (h, next_h);
+                (k_previous, k) = (k, next_k);
+            }
+            _ => break,
+        }
+
+        // What's left is so small that it's just the error of the f64
+        let fraction: f64 = x - x.floor();
+        if fraction < 1e-12 {
+            break;
+        }
+        x = 1.0 / fraction;
+    }
+
+    Rational::new(sign * h, k)
+}
+
+/// The coefficients of the continued fraction of `num / den`, such that
+/// `415/93 = 4 + 1 / (2 + 1 / (6 + 1 / 7))` gives `[4, 2, 6, 7]`. A denominator of 0 gives no
+/// coefficients at all.
+///
+/// It's Euclid's algorithm for the `gcd` again, keeping the quotients instead of throwing them
+/// away. With a negative fraction only the first coefficient is negative, since we round the
+/// quotients down (`div_euclid`) and not towards zero.
+///
+/// # Panics
+///
+/// Panics only for `i64::MIN / -1`, whose single coefficient 2^63 doesn't fit in an i64. Every
+/// other fraction works, since the computation happens on i128.
+pub fn continued_fraction(num: i64, den: i64) -> Vec<i64> {
+    let mut coefficients: Vec<i64> = Vec::new();
+    let (mut num, mut den): (i128, i128) = (num as i128, den as i128);
+
+    if den < 0 {
+        (num, den) = (-num, -den);
+    }
+
+    while den != 0 {
+        let coefficient: i128 = num.div_euclid(den);
+        coefficients.push(i64::try_from(coefficient).expect("continued fraction overflow"));
+        (num, den) = (den, num.rem_euclid(den));
+    }
+
+    coefficients
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The numerator and the denominator, to compare the exact representation of a fraction
+    fn terms(r: Rational) -> (i64, i64) {
+        (r.numerator(), r.denominator())
+    }
+
+    #[test]
+    fn new_reduces_and_moves_the_sign_up() {
+        assert_eq!(terms(Rational::new(2, 4)), (1, 2));
+        assert_eq!(terms(Rational::new(3, -6)), (-1, 2));
+        assert_eq!(terms(Rational::new(-1, -2)), (1, 2));
+        assert_eq!(terms(Rational::new(0, -5)), (0, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_with_a_zero_denominator_panics() {
+        Rational::new(1, 0);
+    }
+
+    #[test]
+    fn decimal_to_fraction_of_simple_values() {
+        assert_eq!(terms(decimal_to_fraction(0.5, 100)), (1, 2));
+        assert_eq!(terms(decimal_to_fraction(1.0 / 3.0, 100)), (1, 3));
+        assert_eq!(terms(decimal_to_fraction(-0.75, 10)), (-3, 4));
+    }
+
+    #[test]
+    fn decimal_to_fraction_of_a_whole_number() {
+        assert_eq!(terms(decimal_to_fraction(7.0, 10)), (7, 1));
+        assert_eq!(terms(decimal_to_fraction(0.0, 10)), (0, 1));
+    }
+
+    #[test]
+    fn decimal_to_fraction_respects_the_max_denominator() {
+        assert_eq!(terms(decimal_to_fraction(std::f64::consts::PI, 1000)), (355, 113));
+        assert_eq!(terms(decimal_to_fraction(std::f64::consts::PI, 100)), (22, 7));
+        assert_eq!(terms(decimal_to_fraction(0.3, 0)), (0, 1));
+    }
+
+    #[test]
+    fn decimal_to_fraction_without_a_fraction() {
+        assert_eq!(terms(decimal_to_fraction(f64::NAN, 10)), (0, 1));
+        assert_eq!(terms(decimal_to_fraction(f64::INFINITY, 10)), (0, 1));
+    }
+
+    #[test]
+    fn to_mixed_string_of_improper_fractions() {
+        assert_eq!(Rational::new(3, 2).to_mixed_string(), "1 1/2");
+        assert_eq!(Rational::new(-4, 3).to_mixed_string(), "-1 1/3");
+    }
+
+    #[test]
+    fn to_mixed_string_of_whole_numbers() {
+        assert_eq!(Rational::new(10, 2).to_mixed_string(), "5");
+        assert_eq!(Rational::new(-6, 3).to_mixed_string(), "-2");
+        assert_eq!(Rational::new(0, 3).to_mixed_string(), "0");
+    }
+
+    #[test]
+    fn to_mixed_string_of_proper_fractions() {
+        assert_eq!(Rational::new(2, 3).to_mixed_string(), "2/3");
+        assert_eq!(Rational::new(-1, 3).to_mixed_string(), "-1/3");
+    }
+
+    #[test]
+    fn equivalent_fractions_are_equal() {
+        assert_eq!(Rational::new(1, 2), Rational::new(2, 4));
+        assert_eq!(Rational::new(-3, 6), Rational::new(1, -2));
+        assert_ne!(Rational::new(1, 2), Rational::new(1, 3));
+    }
+
+    #[test]
+    fn fractions_are_ordered_by_value() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(1, 8));
+        assert!(Rational::new(i64::MAX, i64::MAX - 1) < Rational::new(i64::MAX - 1, i64::MAX - 2));
+    }
+
+    #[test]
+    fn sorting_fractions() {
+        let mut fractions: Vec<Rational> = [(3, 4), (-1, 2), (2, 3), (1, 8)]
+            .into_iter()
+            .map(|(numerator, denominator)| Rational::new(numerator, denominator))
+            .collect();
+        fractions.sort();
+
+        let sorted: Vec<String> = fractions.iter().map(|f| f.to_string()).collect();
+        assert_eq!(sorted, vec!["-1/2", "1/8", "2/3", "3/4"]);
+    }
+
+    #[test]
+    fn adding_and_subtracting_fractions() {
+        let (half, third): (Rational, Rational) = (Rational::new(1, 2), Rational::new(1, 3));
+
+        assert_eq!(terms(half.add(&third)), (5, 6));
+        assert_eq!(terms(half.sub(&third)), (1, 6));
+        assert_eq!(terms(third.sub(&half)), (-1, 6));
+        assert_eq!(terms(half.add(&half)), (1, 1));
+    }
+
+    #[test]
+    fn multiplying_and_dividing_fractions() {
+        let (half, third): (Rational, Rational) = (Rational::new(1, 2), Rational::new(1, 3));
+
+        assert_eq!(terms(half.mul(&third)), (1, 6));
+        assert_eq!(half.div(&third).map(terms), Ok((3, 2)));
+        assert_eq!(Rational::new(2, 3).div(&Rational::new(4, 9)).map(terms), Ok((3, 2)));
+        assert_eq!(half.div(&Rational::new(-1, 4)).map(terms), Ok((-2, 1)));
+    }
+
+    #[test]
+    fn dividing_by_zero_is_an_error() {
+        let zero: Rational = Rational::new(0, 5);
+
+        assert_eq!(Rational::new(1, 2).div(&zero), Err(RationalError::DivisionByZero));
+    }
+
+    #[test]
+    fn operations_dont_overflow_before_reducing() {
+        let big: Rational = Rational::new(i64::MAX, 2);
+
+        assert_eq!(terms(big.mul(&Rational::new(2, i64::MAX))), (1, 1));
+        assert_eq!(terms(big.sub(&big)), (0, 1));
+    }
+
+    #[test]
+    fn continued_fraction_of_a_fraction() {
+        assert_eq!(continued_fraction(415, 93), vec![4, 2, 6, 7]);
+        assert_eq!(continued_fraction(-7, 3), vec![-3, 1, 2]);
+        assert_eq!(continued_fraction(7, -3), vec![-3, 1, 2]);
+    }
+
+    #[test]
+    fn continued_fraction_of_a_whole_number() {
+        assert_eq!(continued_fraction(6, 1), vec![6]);
+        assert_eq!(continued_fraction(0, 4), vec![0]);
+        assert_eq!(continued_fraction(3, 0), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn continued_fraction_at_the_edges_of_i64() {
+        assert_eq!(continued_fraction(i64::MIN, 1), vec![i64::MIN]);
+        assert_eq!(continued_fraction(i64::MAX, -1), vec![-i64::MAX]);
+        assert_eq!(continued_fraction(1, i64::MIN), vec![-1, 1, i64::MAX]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn continued_fraction_of_minus_i64_min_panics() {
+        continued_fraction(i64::MIN, -1);
+    }
+}
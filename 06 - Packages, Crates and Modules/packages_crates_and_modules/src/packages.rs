@@ -96,6 +96,40 @@ pub(crate) fn modules_func() {
     {
         let equation: Eqtn = Eqtn::new(2, String::from("+"));
         println!("{:?}", equation);
+
+        // NumberType is private, but a public method can still tell us something about it
+        println!("{}", equation.describe_numbers());
+    }
+
+    {
+        let info: CrateInfo = crate_info();
+        println!("This is version {} of the package. Chapters: {:?}", info.version, info.chapters);
+    }
+}
+
+/// Some information about the package and the journey so far
+#[derive(Debug)]
+pub struct CrateInfo {
+    pub version: &'static str,
+    pub chapters: Vec<&'static str>,
+}
+
+/// Returns the version of the package and the list of chapters. The version is the one written in
+/// `Cargo.toml`: Cargo passes it to the compiler as the `CARGO_PKG_VERSION` environment variable,
+/// and the `env!` macro reads it at compile time.
+pub fn crate_info() -> CrateInfo {
+    CrateInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        chapters: vec![
+            "basics",
+            "datatypes",
+            "ownership",
+            "structs",
+            "enums",
+            "packages_crates_and_modules",
+            "collections",
+            "generics_and_traits",
+        ],
     }
 }
 
@@ -120,4 +154,31 @@ pub(crate) fn modules_func() {
  * front of the declaration of the module, but it won't work. The error that we would get would
  * always be that the function inside our module is private. What about using `pub` also in front
  * of the function?
- */
\ No newline at end of file
+ */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crate_info_version_has_three_numbers() {
+        let parts: Vec<&str> = crate_info().version.split('.').collect();
+
+        assert_eq!(parts.len(), 3);
+        assert!(parts.iter().all(|part| part.parse::<u64>().is_ok()));
+    }
+
+    #[test]
+    fn crate_info_lists_every_chapter() {
+        assert_eq!(crate_info().chapters, vec![
+            "basics",
+            "datatypes",
+            "ownership",
+            "structs",
+            "enums",
+            "packages_crates_and_modules",
+            "collections",
+            "generics_and_traits",
+        ]);
+    }
+}
@@ -0,0 +1,138 @@
+/// **Closures** are anonymous functions that can be saved in a variable or passed to another
+/// function. They are written with the parameters between pipes, followed by the body:
+///
+/// ```rust
+/// let add_one = |x: i32| x + 1;
+/// ```
+///
+/// Functions can accept closures as parameters thanks to generics and the `Fn` traits: a parameter
+/// of type `F: Fn(&T) -> T` means "any closure that takes a `&T` and returns a `T`".
+pub fn closures() {
+    {
+        let v: Vec<i32> = vec![1, 2, 3, 4, 5, 6];
+
+        let squares: Vec<i32> = apply_to_each(&v, |x| x * x);
+        let evens: Vec<i32> = keep_if(&v, |x| x % 2 == 0);
+
+        println!("Squares: {:?} | Evens: {:?}", squares, evens);
+
+        /* Closures, unlike functions, can also capture the variables of the scope where they're
+         * defined. For instance, here `threshold` is used inside the closure:
+         */
+
+        let threshold: i32 = 3;
+        println!("Greater than {}: {:?}", threshold, keep_if(&v, |x| *x > threshold));
+    }
+
+    /* If a closure modifies a captured variable, then it's a `FnMut` closure, and it must be
+     * declared as `mut`. Here the closure keeps track of how many times it was called, and it
+     * succeeds only from the third call on:
+     */
+
+    {
+        let mut calls: u32 = 0;
+        let result: Result<u32, String> = retry(|| {
+            calls += 1;
+            if calls < 3 { Err(format!("Failed at call {}", calls)) } else { Ok(calls) }
+        }, 5);
+        println!("{:?} after {} calls", result, calls);
+
+        let mut calls: u32 = 0;
+        let result: Result<u32, String> = retry(|| {
+            calls += 1;
+            Err(format!("Failed at call {}", calls))
+        }, 4);
+        println!("{:?} after {} calls", result, calls);
+    }
+}
+
+/// Applies `f` to each element of `v`, returning a new vector with the results
+pub fn apply_to_each<T, F: Fn(&T) -> T>(v: &[T], f: F) -> Vec<T> {
+    let mut result: Vec<T> = Vec::with_capacity(v.len());
+
+    for item in v {
+        result.push(f(item));
+    }
+
+    result
+}
+
+/// Returns a new vector with a copy of the elements of `v` for which `pred` is `true`
+pub fn keep_if<T: Clone, F: Fn(&T) -> bool>(v: &[T], pred: F) -> Vec<T> {
+    let mut result: Vec<T> = Vec::new();
+
+    for item in v {
+        if pred(item) {
+            result.push(item.clone());
+        }
+    }
+
+    result
+}
+
+/// Calls `f` up to `attempts` times, stopping at the first `Ok`. If every call fails, the last
+/// `Err` is returned. `f` is always called at least once, even if `attempts` is 0.
+pub fn retry<T, E, F: FnMut() -> Result<T, E>>(mut f: F, attempts: u32) -> Result<T, E> {
+    let mut result: Result<T, E> = f();
+    let mut tries: u32 = 1;
+
+    while result.is_err() && tries < attempts {
+        result = f();
+        tries += 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_to_each_squares() {
+        assert_eq!(apply_to_each(&[1, 2, 3, 4], |x| x * x), vec![1, 4, 9, 16]);
+        assert!(apply_to_each(&[] as &[i32], |x| x * x).is_empty());
+    }
+
+    #[test]
+    fn keep_if_filters_evens() {
+        assert_eq!(keep_if(&[1, 2, 3, 4, 5, 6], |x| x % 2 == 0), vec![2, 4, 6]);
+        assert!(keep_if(&[1, 3, 5], |x| x % 2 == 0).is_empty());
+    }
+
+    #[test]
+    fn retry_succeeds_on_the_third_try() {
+        let mut calls: u32 = 0;
+        let result: Result<u32, String> = retry(|| {
+            calls += 1;
+            if calls < 3 { Err(format!("call {}", calls)) } else { Ok(calls) }
+        }, 5);
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn retry_returns_the_last_error() {
+        let mut calls: u32 = 0;
+        let result: Result<(), u32> = retry(|| {
+            calls += 1;
+            Err(calls)
+        }, 4);
+
+        assert_eq!(result, Err(4));
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn retry_calls_at_least_once() {
+        let mut calls: u32 = 0;
+        let result: Result<(), ()> = retry(|| {
+            calls += 1;
+            Err(())
+        }, 0);
+
+        assert_eq!(result, Err(()));
+        assert_eq!(calls, 1);
+    }
+}
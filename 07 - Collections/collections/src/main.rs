@@ -1,9 +1,17 @@
 pub mod vectors;
 pub mod strings;
+pub mod memoization;
+pub mod closures;
+pub mod stacks_and_queues;
+pub mod stats;
 
 /// Collections are a kind of data structures that allow to store multiple types of values into one
 /// single value. Some examples are **vectors**, **strings** and **hash maps**.
 fn main() {
     vectors::vectors();
     strings::strings();
+    memoization::memoization();
+    closures::closures();
+    stacks_and_queues::stacks_and_queues();
+    stats::stats();
 }
@@ -0,0 +1,163 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// A [`HashMap`] is a collection that stores pairs of keys and values, and allows to find the value
+/// of a key in constant time. A nice use of hash maps is **memoization**: if a function is pure
+/// (so it always gives the same output for the same input), we can save each result the first time
+/// that we compute it, and just read it from the map the following times.
+///
+/// [`Memoized`] wraps a function together with its cache. The function is a **closure** stored in a
+/// `Box`, since each closure has its own type and we can't know its size at compile time.
+pub struct Memoized<K, V> {
+    cache: HashMap<K, V>,
+    f: Box<dyn Fn(&K) -> V>,
+}
+
+impl<K: Eq + Hash, V: Clone> Memoized<K, V> {
+    pub fn new(f: impl Fn(&K) -> V + 'static) -> Memoized<K, V> {
+        Memoized {
+            cache: HashMap::new(),
+            f: Box::new(f),
+        }
+    }
+
+    /// Returns the result of the function for `key`, computing it only if it's not in the cache
+    pub fn call(&mut self, key: K) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value: V = (self.f)(&key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+}
+
+/// The classic recursive Fibonacci: it's pretty expensive, since it computes the same values over
+/// and over again
+pub fn fibonacci(n: u64) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => fibonacci(n - 1) + fibonacci(n - 2),
+    }
+}
+
+/// Fibonacci computed bottom-up, keeping only the last two numbers: no recursion and no cache
+pub fn fibonacci_iterative(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+
+    // We stop at `current = fib(n - 1)` and `next = fib(n)`, so that we never compute fib(n + 1)
+    let (mut current, mut next): (u64, u64) = (0, 1);
+    for _ in 1..n {
+        (current, next) = (next, current + next);
+    }
+
+    next
+}
+
+/// The recursive Fibonacci again, but this time every result is saved in a [`HashMap`], so each
+/// number is computed only once and the time becomes linear.
+///
+/// The 94th Fibonacci number doesn't fit in a `u64` anymore, so for `n > 93` the sum overflows
+/// (which panics in debug builds).
+pub fn fib_memo(n: u64) -> u64 {
+    fn helper(n: u64, cache: &mut HashMap<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        if let Some(&value) = cache.get(&n) {
+            return value;
+        }
+
+        let value: u64 = helper(n - 1, cache) + helper(n - 2, cache);
+        cache.insert(n, value);
+        value
+    }
+
+    helper(n, &mut HashMap::new())
+}
+
+pub fn memoization() {
+    /* In order to see that the cache works, we count how many times the closure gets called. The
+     * counter is shared between us and the closure, so we use an `Rc<Cell<u32>>`: we'll see what
+     * they are later on, for now it's enough to know that they let both of us update the counter.
+     */
+
+    {
+        let calls: Rc<Cell<u32>> = Rc::new(Cell::new(0));
+        let counter: Rc<Cell<u32>> = Rc::clone(&calls);
+
+        let mut memo_fibonacci: Memoized<u64, u64> = Memoized::new(move |n: &u64| {
+            counter.set(counter.get() + 1);
+            fibonacci(*n)
+        });
+
+        println!("fibonacci(30) = {}", memo_fibonacci.call(30));
+        println!("fibonacci(30) = {} (again)", memo_fibonacci.call(30));
+        println!("fibonacci(20) = {}", memo_fibonacci.call(20));
+
+        // fibonacci(30) was computed only once, even if we asked for it twice, so this prints 2
+        println!("The closure was called {} times", calls.get());
+    }
+
+    /* `Memoized` only caches the calls made from outside: the recursive calls inside `fibonacci`
+     * still compute everything again. To cache those as well, the recursion itself has to go
+     * through the map, as `fib_memo` does:
+     */
+
+    {
+        let first: Vec<u64> = (0..10).map(fib_memo).collect();
+        println!("The first Fibonacci numbers: {:?}", first);
+
+        println!("fib_memo(90) = {}", fib_memo(90));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_calls_use_the_cache() {
+        let calls: Rc<Cell<u32>> = Rc::new(Cell::new(0));
+        let counter: Rc<Cell<u32>> = Rc::clone(&calls);
+        let mut memo: Memoized<u64, u64> = Memoized::new(move |n: &u64| {
+            counter.set(counter.get() + 1);
+            fibonacci(*n)
+        });
+
+        assert_eq!(memo.call(20), 6765);
+        assert_eq!(memo.call(20), 6765);
+        assert_eq!(calls.get(), 1);
+
+        assert_eq!(memo.call(10), 55);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn fib_memo_of_the_first_numbers() {
+        let first: Vec<u64> = (0..10).map(fib_memo).collect();
+
+        assert_eq!(first, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+    }
+
+    #[test]
+    fn fib_memo_matches_the_other_versions() {
+        for n in 0..=25 {
+            assert_eq!(fib_memo(n), fibonacci(n));
+        }
+        for n in 0..=93 {
+            assert_eq!(fib_memo(n), fibonacci_iterative(n));
+        }
+    }
+
+    #[test]
+    fn fibonacci_iterative_of_the_biggest_u64() {
+        assert_eq!(fibonacci_iterative(93), 12_200_160_415_121_876_738);
+    }
+}
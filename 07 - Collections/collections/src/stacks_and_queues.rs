@@ -0,0 +1,282 @@
+use std::collections::VecDeque;
+use std::iter::Rev;
+use std::{slice, vec};
+
+/// A **stack** follows the **Last In, First Out** (**LIFO**) rule, just like the stack memory:
+/// the last element that gets pushed is the first one that gets popped. A vector already works
+/// like that, since both `push` and `pop` work on its end.
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+/// A **queue** follows the **First In, First Out** (**FIFO**) rule, like a queue at the post
+/// office. A `VecDeque` is a vector that can grow and shrink efficiently on both ends, so it's
+/// perfect for adding elements on the back and removing them from the front.
+pub struct Queue<T> {
+    items: VecDeque<T>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Stack<T> {
+        Stack { items: Vec::new() }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+}
+
+impl<T> Queue<T> {
+    pub fn new() -> Queue<T> {
+        Queue { items: VecDeque::new() }
+    }
+
+    pub fn enqueue(&mut self, item: T) {
+        self.items.push_back(item);
+    }
+
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Stack<T> {
+        Stack::new()
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Queue<T> {
+        Queue::new()
+    }
+}
+
+/// A trait shared by the two structures: each one adds and removes elements following its own rule
+pub trait Container<T> {
+    fn add(&mut self, item: T);
+    fn remove(&mut self) -> Option<T>;
+    fn size(&self) -> usize;
+}
+
+impl<T> Container<T> for Stack<T> {
+    fn add(&mut self, item: T) {
+        self.push(item);
+    }
+
+    fn remove(&mut self) -> Option<T> {
+        self.pop()
+    }
+
+    fn size(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl<T> Container<T> for Queue<T> {
+    fn add(&mut self, item: T) {
+        self.enqueue(item);
+    }
+
+    fn remove(&mut self) -> Option<T> {
+        self.dequeue()
+    }
+
+    fn size(&self) -> usize {
+        self.items.len()
+    }
+}
+
+/* A `for` loop works with anything that implements `IntoIterator`. Like a vector, the stack can
+ * be iterated in three ways: by value (`for item in stack`, which consumes it), by reference
+ * (`for item in &stack`) and by mutable reference (`for item in &mut stack`). In all three cases
+ * the elements come from the top of the stack, in the same order in which `pop` would give them.
+ */
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = Rev<vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter().rev()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = Rev<slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter().rev()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Stack<T> {
+    type Item = &'a mut T;
+    type IntoIter = Rev<slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter_mut().rev()
+    }
+}
+
+/// Pops every element off the stack and puts it in a queue. Since the stack gives back its
+/// elements starting from the last one, the queue ends up with the elements in reverse order.
+pub fn stack_to_queue<T>(mut stack: Stack<T>) -> Queue<T> {
+    let mut queue: Queue<T> = Queue::new();
+
+    while let Some(item) = stack.pop() {
+        queue.enqueue(item);
+    }
+
+    queue
+}
+
+/// Adds all the `items` to the container, then removes everything, returning the removed elements
+/// in the order in which they came out
+fn fill_and_empty<C: Container<i32>>(container: &mut C, items: &[i32]) -> Vec<i32> {
+    for &item in items {
+        container.add(item);
+    }
+
+    let mut removed: Vec<i32> = Vec::with_capacity(container.size());
+    while let Some(item) = container.remove() {
+        removed.push(item);
+    }
+
+    removed
+}
+
+pub fn stacks_and_queues() {
+    {
+        let items: [i32; 3] = [1, 2, 3];
+
+        // The same function works with both structures, but the order of the output changes
+        println!("Stack: {:?}", fill_and_empty(&mut Stack::new(), &items));
+        println!("Queue: {:?}", fill_and_empty(&mut Queue::new(), &items));
+    }
+
+    {
+        let mut stack: Stack<i32> = Stack::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        // The stack is moved inside the function, so we can't use it anymore after this line
+        let mut queue: Queue<i32> = stack_to_queue(stack);
+
+        let mut reversed: Vec<i32> = Vec::new();
+        while let Some(item) = queue.dequeue() {
+            reversed.push(item);
+        }
+        println!("From the stack to the queue: {:?}", reversed);
+    }
+
+    {
+        let mut stack: Stack<i32> = Stack::new();
+        for item in [1, 2, 3] {
+            stack.push(item);
+        }
+
+        for item in &mut stack {
+            *item *= 10;
+        }
+
+        for item in &stack {
+            print!("{} ", item);
+        }
+        println!("(the stack is still ours)");
+
+        let items: Vec<i32> = stack.into_iter().collect();
+        println!("Consumed the stack: {:?}", items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_is_lifo_through_the_trait() {
+        assert_eq!(fill_and_empty(&mut Stack::new(), &[1, 2, 3]), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn queue_is_fifo_through_the_trait() {
+        assert_eq!(fill_and_empty(&mut Queue::new(), &[1, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn size_counts_the_elements() {
+        let mut stack: Stack<i32> = Stack::new();
+        let mut queue: Queue<i32> = Queue::new();
+        for item in [1, 2, 3] {
+            stack.add(item);
+            queue.add(item);
+        }
+        stack.remove();
+
+        assert_eq!(stack.size(), 2);
+        assert_eq!(queue.size(), 3);
+    }
+
+    #[test]
+    fn stack_to_queue_reverses_the_order() {
+        let mut stack: Stack<i32> = Stack::new();
+        for item in [1, 2, 3] {
+            stack.push(item);
+        }
+
+        let mut queue: Queue<i32> = stack_to_queue(stack);
+        let mut dequeued: Vec<i32> = Vec::new();
+        while let Some(item) = queue.dequeue() {
+            dequeued.push(item);
+        }
+
+        assert_eq!(dequeued, vec![3, 2, 1]);
+    }
+
+    /// A stack with 1, 2 and 3 pushed in this order
+    fn one_two_three() -> Stack<i32> {
+        let mut stack: Stack<i32> = Stack::new();
+        for item in [1, 2, 3] {
+            stack.push(item);
+        }
+
+        stack
+    }
+
+    #[test]
+    fn iterating_a_stack_by_value() {
+        let items: Vec<i32> = one_two_three().into_iter().collect();
+
+        assert_eq!(items, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn iterating_a_stack_by_reference() {
+        let stack: Stack<i32> = one_two_three();
+        let mut items: Vec<i32> = Vec::new();
+        for item in &stack {
+            items.push(*item);
+        }
+
+        assert_eq!(items, vec![3, 2, 1]);
+        assert_eq!(stack.size(), 3);
+    }
+
+    #[test]
+    fn iterating_a_stack_by_mutable_reference() {
+        let mut stack: Stack<i32> = one_two_three();
+        for item in &mut stack {
+            *item *= 10;
+        }
+
+        assert_eq!(stack.pop(), Some(30));
+        assert_eq!(stack.into_iter().collect::<Vec<i32>>(), vec![20, 10]);
+    }
+}
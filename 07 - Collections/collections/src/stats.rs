@@ -0,0 +1,344 @@
+/// Vectors of numbers are the bread and butter of statistics. The functions of this module all
+/// take a slice of `f64`, so they can work on a vector as well as on an array or on a part of them.
+///
+/// Most statistics make no sense on an empty slice (what's the average of nothing?), so those
+/// functions return an `Option` instead of a number.
+pub fn stats() {
+    {
+        let v: Vec<f64> = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+        println!("Mean of {:?}: {:?}", v, mean(&v));
+        println!("Variance: {:?} | Standard deviation: {:?}", variance(&v), std_dev(&v));
+
+        let empty: Vec<f64> = Vec::new();
+        println!("The variance of an empty vector is {:?}", variance(&empty));
+    }
+
+    /* A percentile needs the values in order, but sorting the slice would change the data of the
+     * caller. That's why `percentile` sorts a copy of it instead.
+     */
+
+    {
+        let v: Vec<f64> = vec![15.0, 20.0, 35.0, 40.0, 50.0];
+
+        for p in [0.0, 30.0, 50.0, 100.0, 120.0] {
+            println!("The {}th percentile of {:?} is {:?}", p, v, percentile(&v, p));
+        }
+    }
+
+    // Normalizing brings values measured on different scales to the same one, from 0 to 1:
+
+    {
+        let v: Vec<f64> = vec![10.0, 15.0, 20.0, 30.0];
+        println!("{:?} normalized is {:?}", v, normalize(&v));
+
+        let flat: Vec<f64> = vec![3.0, 3.0, 3.0];
+        println!("{:?} normalized is {:?}", flat, normalize(&flat));
+    }
+
+    /* Slices have a `windows` method, which gives all the contiguous sub-slices of a given size.
+     * It makes the moving average almost a one-liner:
+     */
+
+    {
+        let v: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+
+        println!("Moving average of {:?} over 2 values: {:?}", v, moving_average(&v, 2));
+        println!("Over 5 values: {:?}", moving_average(&v, 5));
+    }
+
+    // A histogram counts how many values fall in each part of the range, and it can be drawn:
+
+    {
+        let v: Vec<f64> = vec![1.0, 2.0, 2.5, 3.0, 3.5, 3.8, 4.0, 5.0, 6.0, 9.0];
+        let counts: Vec<usize> = histogram(&v, 4);
+
+        for count in &counts {
+            println!("{}", "#".repeat(*count));
+        }
+
+        // Summing the counts bin after bin tells how many values are below each bin's upper bound
+        println!("Cumulative counts: {:?}", cumulative(&counts));
+    }
+
+    /* The quartiles are the 25th and 75th percentiles, and the distance between them (the
+     * interquartile range) tells how spread the middle half of the data is. A value way outside of
+     * it is likely an outlier, for instance a measurement gone wrong:
+     */
+
+    {
+        let v: Vec<f64> = vec![10.0, 12.0, 11.0, 13.0, 12.0, 11.0, 95.0];
+        println!("Outliers of {:?}: {:?}", v, outliers(&v));
+
+        let v: Vec<f64> = vec![10.0, 12.0, 11.0, 13.0, 12.0, 11.0];
+        println!("Outliers of {:?}: {:?}", v, outliers(&v));
+    }
+}
+
+/// The arithmetic mean of the values, or `None` if there are none
+pub fn mean(v: &[f64]) -> Option<f64> {
+    if v.is_empty() {
+        return None;
+    }
+
+    Some(v.iter().sum::<f64>() / v.len() as f64)
+}
+
+/// The population variance of the values, or `None` if there are none.
+///
+/// It's computed in two passes: first the mean, then the average of the squared distances from
+/// it. The one-pass formula `mean(x²) - mean(x)²` would be faster, but when the values are big and
+/// close to each other it subtracts two huge and almost equal numbers, losing most of the digits.
+pub fn variance(v: &[f64]) -> Option<f64> {
+    let mean: f64 = mean(v)?;
+
+    Some(v.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / v.len() as f64)
+}
+
+/// The population standard deviation, the square root of the [`variance`]
+pub fn std_dev(v: &[f64]) -> Option<f64> {
+    variance(v).map(f64::sqrt)
+}
+
+/// The `p`-th percentile of the values, that is the value below which `p`% of them fall. `p` must
+/// be between 0 and 100, so the 0th percentile is the minimum, the 50th is the median and the
+/// 100th is the maximum.
+///
+/// When the percentile falls between two values, it's interpolated linearly between them. Returns
+/// `None` if the slice is empty or `p` is out of range.
+pub fn percentile(v: &[f64], p: f64) -> Option<f64> {
+    if v.is_empty() || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+
+    let mut sorted: Vec<f64> = v.to_vec();
+    // `f64` isn't `Ord` because of NaN, so we sort with `total_cmp`
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let rank: f64 = p / 100.0 * (sorted.len() - 1) as f64;
+    let below: usize = rank.floor() as usize;
+    let above: usize = rank.ceil() as usize;
+
+    Some(sorted[below] + (sorted[above] - sorted[below]) * (rank - below as f64))
+}
+
+/// Scales the values linearly so that the smallest one becomes 0 and the biggest one becomes 1.
+/// If all the values are the same there is no range to scale on (and dividing by it would give
+/// NaN), so in that case every value becomes 0.
+pub fn normalize(v: &[f64]) -> Vec<f64> {
+    let min: f64 = v.iter().copied().fold(f64::INFINITY, f64::min);
+    let max: f64 = v.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range: f64 = max - min;
+
+    v.iter()
+        .map(|x| if range == 0.0 { 0.0 } else { (x - min) / range })
+        .collect()
+}
+
+/// The average of every group of `window` consecutive values, so the result has
+/// `v.len() - window + 1` elements. A window of 0, or bigger than the slice, gives no averages.
+pub fn moving_average(v: &[f64], window: usize) -> Vec<f64> {
+    // `windows` panics with a size of 0, and gives nothing when the size is bigger than the slice
+    if window == 0 {
+        return Vec::new();
+    }
+
+    v.windows(window)
+        .map(|w| w.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+/// Splits the range between the smallest and the biggest value into `bins` parts of the same
+/// width, and counts how many values fall in each of them. Each bin includes its lower bound but
+/// not the upper one, except for the last bin, which also includes the maximum.
+///
+/// When all the values are the same the range has no width, so they all go in the first bin.
+pub fn histogram(v: &[f64], bins: usize) -> Vec<usize> {
+    if bins == 0 {
+        return Vec::new();
+    }
+
+    let mut counts: Vec<usize> = vec![0; bins];
+    let min: f64 = v.iter().copied().fold(f64::INFINITY, f64::min);
+    let max: f64 = v.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range: f64 = max - min;
+
+    for &x in v {
+        let bin: usize = if range == 0.0 {
+            0
+        } else {
+            // The maximum would end up in the bin `bins`, one past the end, so we move it back
+            (((x - min) / range * bins as f64) as usize).min(bins - 1)
+        };
+        counts[bin] += 1;
+    }
+
+    counts
+}
+
+/// The running total of the counts of a [`histogram`]: the element at index `i` is the sum of the
+/// counts of the bins from `0` to `i`, so the last one is the total number of values.
+pub fn cumulative(counts: &[usize]) -> Vec<usize> {
+    /* `scan` is like `map`, but it also carries a state from an element to the next one: here the
+     * state is the total so far.
+     */
+    counts
+        .iter()
+        .scan(0, |total, &count| {
+            *total += count;
+            Some(*total)
+        })
+        .collect()
+}
+
+/// The values that are more than 1.5 times the interquartile range below the first quartile or
+/// above the third one, in the order in which they appear. With less than 4 values the quartiles
+/// don't mean much, so no value is considered an outlier.
+pub fn outliers(v: &[f64]) -> Vec<f64> {
+    if v.len() < 4 {
+        return Vec::new();
+    }
+
+    // The slice isn't empty, and 25 and 75 are valid percentiles, so these can't be None
+    let q1: f64 = percentile(v, 25.0).unwrap();
+    let q3: f64 = percentile(v, 75.0).unwrap();
+    let iqr: f64 = q3 - q1;
+
+    v.iter()
+        .copied()
+        .filter(|&x| x < q1 - 1.5 * iqr || x > q3 + 1.5 * iqr)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variance_and_std_dev_of_a_known_dataset() {
+        let v: [f64; 8] = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+        assert_eq!(mean(&v), Some(5.0));
+        assert_eq!(variance(&v), Some(4.0));
+        assert_eq!(std_dev(&v), Some(2.0));
+    }
+
+    #[test]
+    fn variance_of_big_close_values() {
+        // The one-pass formula would lose all the digits here
+        let v: [f64; 3] = [1e9 + 1.0, 1e9 + 2.0, 1e9 + 3.0];
+
+        assert_eq!(variance(&v), Some(2.0 / 3.0));
+    }
+
+    #[test]
+    fn variance_and_std_dev_of_nothing() {
+        assert_eq!(mean(&[]), None);
+        assert_eq!(variance(&[]), None);
+        assert_eq!(std_dev(&[]), None);
+    }
+
+    #[test]
+    fn percentile_median_min_and_max() {
+        let v: [f64; 5] = [35.0, 15.0, 50.0, 20.0, 40.0];
+
+        assert_eq!(percentile(&v, 50.0), Some(35.0));
+        assert_eq!(percentile(&v, 0.0), Some(15.0));
+        assert_eq!(percentile(&v, 100.0), Some(50.0));
+    }
+
+    #[test]
+    fn percentile_interpolates_between_values() {
+        assert_eq!(percentile(&[15.0, 20.0, 35.0, 40.0, 50.0], 62.5), Some(37.5));
+        assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0], 50.0), Some(2.5));
+    }
+
+    #[test]
+    fn percentile_leaves_the_input_alone() {
+        let v: Vec<f64> = vec![3.0, 1.0, 2.0];
+        percentile(&v, 50.0);
+
+        assert_eq!(v, vec![3.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn percentile_out_of_range_or_empty() {
+        assert_eq!(percentile(&[1.0, 2.0], 120.0), None);
+        assert_eq!(percentile(&[1.0, 2.0], -1.0), None);
+        assert_eq!(percentile(&[1.0, 2.0], f64::NAN), None);
+        assert_eq!(percentile(&[], 50.0), None);
+    }
+
+    #[test]
+    fn normalize_a_spread_dataset() {
+        assert_eq!(normalize(&[10.0, 15.0, 20.0, 30.0]), vec![0.0, 0.25, 0.5, 1.0]);
+        assert_eq!(normalize(&[-1.0, 1.0]), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn normalize_all_equal_values() {
+        assert_eq!(normalize(&[3.0, 3.0, 3.0]), vec![0.0, 0.0, 0.0]);
+        assert!(normalize(&[]).is_empty());
+    }
+
+    #[test]
+    fn moving_average_over_two_values() {
+        assert_eq!(moving_average(&[1.0, 2.0, 3.0, 4.0], 2), vec![1.5, 2.5, 3.5]);
+        assert_eq!(moving_average(&[1.0, 2.0, 3.0, 4.0], 4), vec![2.5]);
+    }
+
+    #[test]
+    fn moving_average_with_degenerate_windows() {
+        assert!(moving_average(&[1.0, 2.0, 3.0, 4.0], 0).is_empty());
+        assert!(moving_average(&[1.0, 2.0, 3.0, 4.0], 5).is_empty());
+        assert!(moving_average(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn histogram_of_a_known_distribution() {
+        let v: [f64; 10] = [1.0, 2.0, 2.5, 3.0, 3.5, 3.8, 4.0, 5.0, 6.0, 9.0];
+
+        assert_eq!(histogram(&v, 4), vec![3, 4, 2, 1]);
+        assert_eq!(histogram(&v, 1), vec![10]);
+    }
+
+    #[test]
+    fn histogram_puts_the_maximum_in_the_last_bin() {
+        assert_eq!(histogram(&[0.0, 10.0], 5), vec![1, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn histogram_of_equal_values_or_no_bins() {
+        assert_eq!(histogram(&[2.0, 2.0], 3), vec![2, 0, 0]);
+        assert_eq!(histogram(&[], 3), vec![0, 0, 0]);
+        assert!(histogram(&[1.0, 2.0], 0).is_empty());
+    }
+
+    #[test]
+    fn cumulative_running_total() {
+        assert_eq!(cumulative(&[1, 2, 3]), vec![1, 3, 6]);
+        assert_eq!(cumulative(&[3, 4, 2, 1]).last(), Some(&10));
+    }
+
+    #[test]
+    fn cumulative_of_nothing() {
+        assert!(cumulative(&[]).is_empty());
+    }
+
+    #[test]
+    fn outliers_finds_a_clear_outlier() {
+        assert_eq!(outliers(&[10.0, 12.0, 11.0, 13.0, 12.0, 11.0, 95.0]), vec![95.0]);
+        assert_eq!(outliers(&[-80.0, 10.0, 12.0, 11.0, 13.0, 12.0, 11.0]), vec![-80.0]);
+    }
+
+    #[test]
+    fn outliers_without_outliers() {
+        assert!(outliers(&[10.0, 12.0, 11.0, 13.0, 12.0, 11.0]).is_empty());
+    }
+
+    #[test]
+    fn outliers_of_tiny_inputs() {
+        assert!(outliers(&[]).is_empty());
+        assert!(outliers(&[1.0, 2.0, 1000.0]).is_empty());
+    }
+}
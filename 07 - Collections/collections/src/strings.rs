@@ -1,3 +1,5 @@
+use crate::vectors;
+
 pub fn strings() {
     /*  In Rust, there are only two types of strings: the "str" type and the "String" type. Another
      *  core difference between the two types is that the "String" type is a vector of bytes. This
@@ -15,5 +17,524 @@ pub fn strings() {
         println!("String 1: {:?}\nString 2: {:?}", a_string, another_string);
     }
 
+    /*  Since a String is made of words separated by spaces, we can split it and build a new one.
+     *  For instance, we can wrap a long text so that each line is at most 20 characters long:
+     */
+
+    {
+        let a_text: &str = "Collections are a kind of data structures that allow to store multiple \
+                            values into one single value";
+
+        println!("{}", wrap_text(a_text, 20));
+
+        // We can also add some spaces in front of each line, for instance to show a nested matrix
+        let a_matrix: String = vectors::format_matrix(&vectors::multiplication_table(3));
+        println!("A matrix:\n{}", indent(&a_matrix, 4));
+    }
+
+    // Working on the characters of a string, we can also hide part of it:
+
+    {
+        println!("{} | {}", mask_middle("4111111111111111", 4, 4), mask_middle("abc", 2, 2));
+    }
+
+    /*  The edit distance between two strings is the minimum number of single character insertions,
+     *  deletions or substitutions needed to turn one string into the other:
+     */
+
+    {
+        println!("kitten -> sitting: {} | rust -> rust: {} | \"\" -> crab: {}",
+                 edit_distance("kitten", "sitting"), edit_distance("rust", "rust"),
+                 edit_distance("", "crab"));
+    }
+
+    // A nice use of the edit distance is suggesting what the user probably meant to type
+
+    {
+        let demos: [&str; 4] = ["vectors", "strings", "enums", "structs"];
+
+        for input in ["enmus", "strigns", "potato"] {
+            match suggest(input, &demos) {
+                Some(suggestion) => {
+                    println!("Unknown demo {:?}, did you mean {:?}?", input, suggestion);
+                }
+                None => println!("Unknown demo {:?}", input),
+            }
+        }
+    }
+
+    /*  Since a String is a vector of bytes, cutting it every `n` bytes may cut a character in two:
+     *  "è" takes two bytes, for instance. To cut it every `n` characters we have to go through
+     *  `chars()` instead:
+     */
+
+    {
+        println!("{:?} | {:?} | {:?}", chunk_chars("abcdefgh", 3), chunk_chars("crème brûlée", 4),
+                 chunk_chars("abc", 0));
+    }
+
+    /*  Run-length encoding is one of the simplest ways of compressing a string: each run of equal
+     *  characters is replaced by the character followed by the length of the run.
+     */
+
+    {
+        let encoded: String = rle_encode("aaabbcddddd");
+        println!("{:?} -> {:?}", encoded, rle_decode(&encoded));
+
+        for bad in ["a3b", "3a", "a0", "a99999999999"] {
+            println!("{:?} -> {:?}", bad, rle_decode(bad));
+        }
+    }
+
+    /*  Searching a substring gives byte indices, not character indices, since that's what we need
+     *  to slice the string. After a match the search goes on after its end, so "aa" appears only
+     *  twice in "aaaa" and not three times:
+     */
+
+    {
+        println!("{:?} | {:?} | {:?}", find_all("abcabcab", "ab"), find_all("abc", "x"),
+                 find_all("aaaa", "aa"));
+
+        // Knowing where each occurrence starts, we can replace only one of them
+        let text: &str = "one fish, two fish, red fish";
+        println!("{:?}", replace_nth(text, "fish", "cat", 2));
+        println!("{:?}", replace_nth(text, "fish", "cat", 4));
+    }
+
+    /*  Since a String is a vector of bytes, `as_bytes()` lets us look at them directly. A checksum
+     *  reduces all the bytes to a small number: if a file gets corrupted while it's copied, its
+     *  checksum will most likely change as well.
+     */
+
+    {
+        for text in ["", "abcde", "abced"] {
+            println!("Checksum of {:?}: {:#06x}", text, simple_checksum(text.as_bytes()));
+        }
+    }
+}
+
+/// Adds `spaces` spaces in front of each line of `s`. Empty lines are left as they are, so that we
+/// don't end up with lines made only of spaces.
+pub fn indent(s: &str, spaces: usize) -> String {
+    let padding: String = " ".repeat(spaces);
+
+    s.split('\n')
+        .map(|line| if line.is_empty() { line.to_string() } else { format!("{}{}", padding, line) })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Breaks `s` into lines that are at most `width` characters long. Lines are broken only at
+/// spaces, so a word longer than `width` ends up alone on its own line.
+pub fn wrap_text(s: &str, width: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line: String = String::new();
+
+    for word in s.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(line);
+            line = String::new();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+/// Replaces the characters in the middle of `s` with `*`, keeping the first `keep_start` and the
+/// last `keep_end` characters. If there is nothing left to hide in the middle, `s` is returned as
+/// it is.
+pub fn mask_middle(s: &str, keep_start: usize, keep_end: usize) -> String {
+    let length: usize = s.chars().count();
+    // Saturating, so that two huge `keep_start` and `keep_end` don't overflow
+    if keep_start.saturating_add(keep_end) >= length {
+        return s.to_string();
+    }
+
+    s.chars()
+        .enumerate()
+        .map(|(i, c)| if i < keep_start || i >= length - keep_end { c } else { '*' })
+        .collect()
+}
+
+/// Computes the Levenshtein distance between `a` and `b`. The cell `(i, j)` of the table holds the
+/// distance between the first `i` characters of `a` and the first `j` characters of `b`, and it
+/// can be computed from the three cells on its top and on its left.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut table: Vec<Vec<usize>> = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution: usize = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            table[i][j] = (table[i - 1][j] + 1)
+                .min(table[i][j - 1] + 1)
+                .min(table[i - 1][j - 1] + substitution);
+        }
+    }
+
+    table[a.len()][b.len()]
+}
+
+/// Returns the option closest to `input`, as long as it's within an edit distance of 2. If two
+/// options are equally close, the first one is returned.
+pub fn suggest(input: &str, options: &[&str]) -> Option<String> {
+    options
+        .iter()
+        .map(|option| (edit_distance(input, option), option))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, option)| option.to_string())
+}
+
+/// Splits `s` in pieces of `size` characters each, except for the last one, which may be shorter.
+/// A size of 0 gives no pieces at all.
+pub fn chunk_chars(s: &str, size: usize) -> Vec<String> {
+    if size == 0 {
+        return Vec::new();
+    }
+
+    let chars: Vec<char> = s.chars().collect();
+    chars.chunks(size).map(|chunk| chunk.iter().collect()).collect()
+}
+
+/// Encodes `s` with run-length encoding, so `"aaabb"` becomes `"a3b2"`. Every character is
+/// followed by its count, even when it's 1, so that [`rle_decode`] always knows where a run ends.
+///
+/// The counts are written with digits, so a string that contains digits itself can't be decoded
+/// back correctly: `"a1"` becomes `"a111"`, which reads as 111 times `a`.
+pub fn rle_encode(s: &str) -> String {
+    let mut encoded: String = String::new();
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let mut count: usize = 1;
+        while chars.next_if_eq(&c).is_some() {
+            count += 1;
+        }
+
+        encoded.push(c);
+        encoded.push_str(&count.to_string());
+    }
+
+    encoded
+}
+
+/// The errors that may occur while decoding a run-length encoded string
+#[derive(Debug, PartialEq)]
+pub enum RleError {
+    /// The string starts with a count, which has no character to repeat
+    MissingCharacter,
+    /// The character is not followed by its count
+    MissingCount(char),
+    /// The character has a count of 0, which an encoder never writes
+    ZeroCount(char),
+    /// The count of the character doesn't fit in a `usize`
+    CountTooBig(char),
+    /// The decoded string would be longer than [`RLE_MAX_DECODED_LEN`] characters
+    TooLong,
+}
+
+/// The longest string that [`rle_decode`] gives back. A few bytes such as `"a99999999999"` would
+/// otherwise ask for hundreds of gigabytes, and running out of memory aborts the whole program.
+pub const RLE_MAX_DECODED_LEN: usize = 1 << 20;
+
+/// Decodes a string produced by [`rle_encode`], so `"a3b2"` becomes `"aaabb"`
+pub fn rle_decode(s: &str) -> Result<String, RleError> {
+    let mut decoded: String = String::new();
+    let mut length: usize = 0;
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() {
+            return Err(RleError::MissingCharacter);
+        }
+
+        let mut digits: String = String::new();
+        while let Some(digit) = chars.next_if(|d| d.is_ascii_digit()) {
+            digits.push(digit);
+        }
+
+        if digits.is_empty() {
+            return Err(RleError::MissingCount(c));
+        }
+
+        let count: usize = digits.parse().map_err(|_| RleError::CountTooBig(c))?;
+        if count == 0 {
+            return Err(RleError::ZeroCount(c));
+        }
+
+        length = length.checked_add(count).ok_or(RleError::TooLong)?;
+        if length > RLE_MAX_DECODED_LEN {
+            return Err(RleError::TooLong);
+        }
+
+        decoded.extend(std::iter::repeat_n(c, count));
+    }
+
+    Ok(decoded)
+}
+
+/// Returns the byte index of every occurrence of `needle` in `haystack`, without overlaps. An
+/// empty needle would match everywhere, so in that case no index is returned.
+pub fn find_all(haystack: &str, needle: &str) -> Vec<usize> {
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    haystack.match_indices(needle).map(|(index, _)| index).collect()
+}
+
+/// Replaces only the `n`-th occurrence of `needle`, counting from 1, with `replacement`. If there
+/// are less than `n` occurrences the string is returned unchanged.
+pub fn replace_nth(haystack: &str, needle: &str, replacement: &str, n: usize) -> String {
+    // `checked_sub` turns n = 0 into None, since there is no 0th occurrence
+    match n.checked_sub(1).and_then(|i| find_all(haystack, needle).get(i).copied()) {
+        Some(start) => {
+            let end: usize = start + needle.len();
+            format!("{}{}{}", &haystack[..start], replacement, &haystack[end..])
+        }
+        None => haystack.to_string(),
+    }
+}
+
+/// Computes the Fletcher-16 checksum of `data`. It keeps two sums modulo 255: the first is the sum
+/// of the bytes, while the second is the sum of all the values taken by the first. Thanks to the
+/// second sum, swapping two bytes changes the checksum, which wouldn't happen with the first alone.
+///
+/// The second sum goes in the upper 8 bits of the result, and the first one in the lower 8 bits.
+pub fn simple_checksum(data: &[u8]) -> u32 {
+    let mut sum1: u32 = 0;
+    let mut sum2: u32 = 0;
+
+    for &byte in data {
+        sum1 = (sum1 + byte as u32) % 255;
+        sum2 = (sum2 + sum1) % 255;
+    }
+
+    (sum2 << 8) | sum1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_text_of_a_paragraph() {
+        let text: &str = "the quick brown fox jumps over the lazy dog";
+
+        assert_eq!(wrap_text(text, 10), "the quick\nbrown fox\njumps over\nthe lazy\ndog");
+        assert!(wrap_text(text, 15).lines().all(|line| line.chars().count() <= 15));
+    }
+
+    #[test]
+    fn wrap_text_puts_a_long_word_alone() {
+        assert_eq!(wrap_text("a supercalifragilistic word", 5), "a\nsupercalifragilistic\nword");
+    }
+
+    #[test]
+    fn wrap_text_with_width_zero() {
+        assert_eq!(wrap_text("one two", 0), "one\ntwo");
+        assert_eq!(wrap_text("", 0), "");
+    }
+
+    #[test]
+    fn indent_every_line() {
+        assert_eq!(indent("a\nb\n\nc", 2), "  a\n  b\n\n  c");
+    }
+
+    #[test]
+    fn indent_an_empty_string() {
+        assert_eq!(indent("", 4), "");
+    }
+
+    #[test]
+    fn indent_keeps_the_trailing_newline() {
+        assert_eq!(indent("a\nb\n", 3), "   a\n   b\n");
+    }
+
+    #[test]
+    fn mask_middle_of_a_card_number() {
+        assert_eq!(mask_middle("4111111111111111", 4, 4), "4111********1111");
+        assert_eq!(mask_middle("perché", 1, 1), "p****é");
+    }
+
+    #[test]
+    fn mask_middle_with_overlapping_keeps() {
+        assert_eq!(mask_middle("secret", 3, 3), "secret");
+        assert_eq!(mask_middle("secret", 5, 4), "secret");
+        assert_eq!(mask_middle("", 0, 0), "");
+    }
+
+    #[test]
+    fn mask_middle_with_huge_keeps_does_not_overflow() {
+        assert_eq!(mask_middle("secret", usize::MAX, usize::MAX), "secret");
+        assert_eq!(mask_middle("secret", 1, usize::MAX), "secret");
+    }
+
+    #[test]
+    fn edit_distance_of_kitten_and_sitting() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("sitting", "kitten"), 3);
+    }
+
+    #[test]
+    fn edit_distance_of_identical_strings() {
+        assert_eq!(edit_distance("rust", "rust"), 0);
+        assert_eq!(edit_distance("", ""), 0);
+    }
+
+    #[test]
+    fn edit_distance_from_an_empty_string() {
+        assert_eq!(edit_distance("", "crab"), 4);
+        assert_eq!(edit_distance("perché", ""), 6);
+    }
+
+    #[test]
+    fn suggest_the_closest_option() {
+        let options: [&str; 3] = ["basics", "enums", "structs"];
+
+        assert_eq!(suggest("enmus", &options), Some(String::from("enums")));
+        assert_eq!(suggest("struct", &options), Some(String::from("structs")));
+    }
+
+    #[test]
+    fn suggest_nothing_for_unrelated_input() {
+        assert_eq!(suggest("collections", &["basics", "enums", "structs"]), None);
+        assert_eq!(suggest("enums", &[]), None);
+    }
 
-}
\ No newline at end of file
+    #[test]
+    fn suggest_the_first_of_equally_close_options() {
+        assert_eq!(suggest("cat", &["bat", "hat"]), Some(String::from("bat")));
+    }
+
+    #[test]
+    fn chunk_chars_of_ascii_text() {
+        assert_eq!(chunk_chars("abcdefg", 3), vec!["abc", "def", "g"]);
+        assert_eq!(chunk_chars("abcdef", 3), vec!["abc", "def"]);
+    }
+
+    #[test]
+    fn chunk_chars_does_not_split_accented_letters() {
+        assert_eq!(chunk_chars("perché così", 4), vec!["perc", "hé c", "osì"]);
+        assert_eq!(chunk_chars("èé", 1), vec!["è", "é"]);
+    }
+
+    #[test]
+    fn chunk_chars_with_size_zero_or_no_text() {
+        assert!(chunk_chars("abc", 0).is_empty());
+        assert!(chunk_chars("", 2).is_empty());
+    }
+
+    #[test]
+    fn rle_encode_writes_every_count() {
+        assert_eq!(rle_encode("aaabb"), "a3b2");
+        assert_eq!(rle_encode("abc"), "a1b1c1");
+        assert_eq!(rle_encode(""), "");
+    }
+
+    #[test]
+    fn rle_round_trips() {
+        for text in ["aaabbcddddd", "", "x", "perché", "  spaces  ", &"z".repeat(120)] {
+            assert_eq!(rle_decode(&rle_encode(text)), Ok(text.to_string()));
+        }
+    }
+
+    #[test]
+    fn rle_decode_rejects_malformed_input() {
+        assert_eq!(rle_decode("a3b"), Err(RleError::MissingCount('b')));
+        assert_eq!(rle_decode("3a"), Err(RleError::MissingCharacter));
+        assert_eq!(rle_decode("a0"), Err(RleError::ZeroCount('a')));
+        assert_eq!(rle_decode("a99999999999999999999999"), Err(RleError::CountTooBig('a')));
+    }
+
+    #[test]
+    fn rle_decode_rejects_strings_too_long() {
+        assert_eq!(rle_decode("a99999999999"), Err(RleError::TooLong));
+        assert_eq!(rle_decode(&format!("a{}b1", RLE_MAX_DECODED_LEN)), Err(RleError::TooLong));
+        assert_eq!(rle_decode(&format!("a{}", usize::MAX)), Err(RleError::TooLong));
+    }
+
+    #[test]
+    fn rle_decode_up_to_the_limit() {
+        let decoded: String = rle_decode(&format!("a{}", RLE_MAX_DECODED_LEN)).unwrap();
+
+        assert_eq!(decoded.len(), RLE_MAX_DECODED_LEN);
+    }
+
+    #[test]
+    fn find_all_of_several_occurrences() {
+        assert_eq!(find_all("one fish, two fish, red fish", "fish"), vec![4, 14, 24]);
+        assert_eq!(find_all("così così", "sì"), vec![2, 8]);
+    }
+
+    #[test]
+    fn find_all_without_occurrences() {
+        assert!(find_all("hello", "z").is_empty());
+        assert!(find_all("hello", "").is_empty());
+        assert!(find_all("", "a").is_empty());
+    }
+
+    #[test]
+    fn find_all_does_not_overlap() {
+        assert_eq!(find_all("aaaa", "aa"), vec![0, 2]);
+        assert_eq!(find_all("ababa", "aba"), vec![0]);
+    }
+
+    #[test]
+    fn replace_nth_replaces_only_that_occurrence() {
+        let text: &str = "one fish, two fish, red fish";
+
+        assert_eq!(replace_nth(text, "fish", "cat", 2), "one fish, two cat, red fish");
+        assert_eq!(replace_nth(text, "fish", "cat", 1), "one cat, two fish, red fish");
+    }
+
+    #[test]
+    fn replace_nth_out_of_range() {
+        let text: &str = "one fish, two fish, red fish";
+
+        assert_eq!(replace_nth(text, "fish", "cat", 4), text);
+        assert_eq!(replace_nth(text, "fish", "cat", 0), text);
+    }
+
+    #[test]
+    fn replace_nth_of_a_missing_needle() {
+        assert_eq!(replace_nth("hello", "bye", "hi", 1), "hello");
+        assert_eq!(replace_nth("hello", "", "hi", 1), "hello");
+    }
+
+    #[test]
+    fn simple_checksum_of_nothing() {
+        assert_eq!(simple_checksum(&[]), 0);
+    }
+
+    #[test]
+    fn simple_checksum_of_known_inputs() {
+        assert_eq!(simple_checksum(b"abcde"), 0xc8f0);
+        assert_eq!(simple_checksum(b"abcdef"), 0x2057);
+        assert_eq!(simple_checksum(b"abcdefgh"), 0x0627);
+    }
+
+    #[test]
+    fn simple_checksum_notices_swapped_bytes() {
+        assert_ne!(simple_checksum(b"abcde"), simple_checksum(b"abced"));
+        assert_ne!(simple_checksum(b"hello"), simple_checksum(b"world"));
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// Vectors are a data structure that allow to store an indefinite number of elements of the
 /// **same types**. A vector is initialized as follows:
 ///
@@ -64,6 +66,14 @@ pub fn vectors() {
          *  In order to avoid that, Rust enforces this rule.
          */
 
+        /*  We can actually see when the vector moves: its capacity (how many items fit in the space
+         *  that it has now) grows only when it's full, and each time it grows a lot, so that moving
+         *  doesn't happen too often.
+         */
+
+        let capacities: Vec<usize> = observe_growth(20);
+        println!("Capacity after each push: {:?}", capacities);
+
         {
             // Iterating over a vector can be done with a for loop, such as the following:
             let mut v = vec![1, 2, 3, 4, 5];
@@ -91,14 +101,7 @@ pub fn vectors() {
          */
 
         {
-            #[derive(Debug)]
-            enum VariousVectorTypes {
-                Integer(i32),
-                Floating(f32),
-                Boolean(bool),
-                Text(String),
-            }
-
+            // The enum `VariousVectorTypes` is defined at the end of this file
             let v: Vec<VariousVectorTypes> = vec![VariousVectorTypes::Integer(4),
                                                   VariousVectorTypes::Floating(3.72),
                                                   VariousVectorTypes::Boolean(true),
@@ -109,9 +112,471 @@ pub fn vectors() {
                 println!("{:?}", i);
             }
 
+            // With `Display` we print only the value inside, without the name of the variant
+            for i in &v {
+                println!("{}", i);
+            }
+
+            /*  Writing the name of the variant each time is quite verbose: thanks to the `From`
+             *  implementations below, `.into()` picks the right variant from the type of the value
+             */
+            let w: Vec<VariousVectorTypes> = vec![4.into(),
+                                                  3.72.into(),
+                                                  true.into(),
+                                                  String::from("Hello there").into()];
+            println!("{:?}", w);
+
+            // We can also go back to one vector for each type, by moving each element in its own
+            let (integers, floats, booleans, texts) = into_buckets(v);
+            println!("{:?} {:?} {:?} {:?}", integers, floats, booleans, texts);
+
             /*  Of course, as any other data structure in Rust, once a vector goes out of scope, it
              *  gets deleted from the memory
              */
         }
     }
-}
\ No newline at end of file
+
+    /* Vectors can also be passed to functions as slices (`&[T]`), so that the function doesn't take
+     * the ownership of the vector. For instance, `running_max` walks a slice and builds a new vector
+     * where each element is the biggest number seen so far:
+     */
+
+    {
+        let v: Vec<i32> = vec![3, 1, 4, 1, 5];
+
+        println!("Running maxima of {:?}: {:?}", v, running_max(&v));
+
+        let v: Vec<i32> = vec![1, 2, 1, 2, 3, 4];
+
+        println!("The longest increasing run of {:?} is {} long", v, longest_increasing_run(&v));
+    }
+
+    /* When a function can fail, it returns a `Result`. For instance, the dot product makes sense
+     * only between two vectors of the same length:
+     */
+
+    {
+        let a: Vec<i32> = vec![1, 2, 3];
+        let b: Vec<i32> = vec![4, 5, 6];
+        let c: Vec<i32> = vec![7, 8];
+
+        println!("{:?} · {:?} = {:?}", a, b, dot_product(&a, &b));
+        println!("{:?} · {:?} = {:?}", a, c, dot_product(&a, &c));
+    }
+
+    // Vectors can also contain other vectors, so we can use them to make a matrix:
+
+    {
+        let table: Vec<Vec<u32>> = multiplication_table(5);
+
+        println!("{}", format_matrix(&table));
+    }
+
+    /* Removing an element from the middle of a vector shifts all the following ones back by one.
+     * `swap_remove` would be faster, since it moves the last element in the hole, but it changes
+     * the order of the vector:
+     */
+
+    {
+        let mut v: Vec<i32> = vec![1, 2, 3, 2, 1];
+
+        println!("Removed 2: {} -> {:?}", remove_value(&mut v, &2), v);
+        println!("Removed 7: {} -> {:?}", remove_value(&mut v, &7), v);
+
+        // If the vector is kept sorted, we can find where to insert each value with a binary search
+        let mut sorted: Vec<i32> = Vec::new();
+        for value in [5, 1, 9, 5, 3] {
+            insert_sorted(&mut sorted, value);
+        }
+        println!("Inserted in order: {:?}", sorted);
+    }
+}
+
+/// Pushes `pushes` numbers in an empty vector, and returns the capacity that the vector has after
+/// each push. The exact growth strategy is up to the standard library, but today it doubles the
+/// capacity every time the vector is full.
+pub fn observe_growth(pushes: usize) -> Vec<usize> {
+    let mut v: Vec<usize> = Vec::new();
+    let mut capacities: Vec<usize> = Vec::with_capacity(pushes);
+
+    for i in 0..pushes {
+        v.push(i);
+        capacities.push(v.capacity());
+    }
+
+    capacities
+}
+
+/// Returns a vector where the element at index `i` is the maximum of `v[0..=i]`
+pub fn running_max(v: &[i32]) -> Vec<i32> {
+    let mut result: Vec<i32> = Vec::with_capacity(v.len());
+
+    for &item in v {
+        match result.last() {
+            Some(&max) if max > item => result.push(max),
+            _ => result.push(item),
+        }
+    }
+
+    result
+}
+
+/// Returns the length of the longest strictly increasing run of consecutive elements of `v`
+pub fn longest_increasing_run(v: &[i32]) -> usize {
+    if v.is_empty() {
+        return 0;
+    }
+
+    let mut longest: usize = 1;
+    let mut current: usize = 1;
+
+    for i in 1..v.len() {
+        if v[i] > v[i - 1] {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 1;
+        }
+    }
+
+    longest
+}
+
+/// The error returned by [`dot_product`] when the two slices have different lengths
+#[derive(Debug, PartialEq)]
+pub struct LengthMismatch {
+    pub left: usize,
+    pub right: usize,
+}
+
+/// Computes the dot product of `a` and `b`. The sum is done with `i64`, since multiplying two
+/// `i32` numbers may easily overflow an `i32`.
+pub fn dot_product(a: &[i32], b: &[i32]) -> Result<i64, LengthMismatch> {
+    if a.len() != b.len() {
+        return Err(LengthMismatch {
+            left: a.len(),
+            right: b.len(),
+        });
+    }
+
+    Ok(a.iter().zip(b).map(|(&x, &y)| x as i64 * y as i64).sum())
+}
+
+/// Returns the `n`×`n` multiplication table, where the cell `(i, j)` holds `(i + 1) * (j + 1)`
+pub fn multiplication_table(n: u32) -> Vec<Vec<u32>> {
+    (1..=n).map(|i| (1..=n).map(|j| i * j).collect()).collect()
+}
+
+/// Formats a matrix one row per line, aligning the columns to the right
+pub fn format_matrix(matrix: &[Vec<u32>]) -> String {
+    let width: usize = matrix
+        .iter()
+        .flatten()
+        .map(|cell| cell.to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| format!("{:>width$}", cell, width = width))
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Removes the first element equal to `target`, keeping the order of the others. Returns whether
+/// an element was found and removed.
+pub fn remove_value<T: PartialEq>(v: &mut Vec<T>, target: &T) -> bool {
+    match v.iter().position(|item| item == target) {
+        Some(index) => {
+            v.remove(index);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Inserts `value` in a vector that's already sorted, in the position that keeps it sorted.
+/// `binary_search` returns `Ok` with the index of an equal element if there is one, or `Err` with
+/// the index where the value should go: in both cases that's a good place for the new value.
+pub fn insert_sorted(v: &mut Vec<i32>, value: i32) {
+    let index: usize = match v.binary_search(&value) {
+        Ok(index) | Err(index) => index,
+    };
+
+    v.insert(index, value);
+}
+
+/// An enum that allows to store values of different types in the same vector
+#[derive(Debug)]
+pub enum VariousVectorTypes {
+    Integer(i32),
+    Floating(f32),
+    Boolean(bool),
+    Text(String),
+}
+
+/* Each `From` implementation tells Rust how to build a VariousVectorTypes out of a value. We get
+ * `Into` for free: `4.into()` works wherever a VariousVectorTypes is expected.
+ */
+
+impl From<i32> for VariousVectorTypes {
+    fn from(value: i32) -> VariousVectorTypes {
+        VariousVectorTypes::Integer(value)
+    }
+}
+
+impl From<f32> for VariousVectorTypes {
+    fn from(value: f32) -> VariousVectorTypes {
+        VariousVectorTypes::Floating(value)
+    }
+}
+
+impl From<bool> for VariousVectorTypes {
+    fn from(value: bool) -> VariousVectorTypes {
+        VariousVectorTypes::Boolean(value)
+    }
+}
+
+impl From<String> for VariousVectorTypes {
+    fn from(value: String) -> VariousVectorTypes {
+        VariousVectorTypes::Text(value)
+    }
+}
+
+impl fmt::Display for VariousVectorTypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VariousVectorTypes::Integer(i) => write!(f, "{}", i),
+            VariousVectorTypes::Floating(x) => write!(f, "{}", x),
+            VariousVectorTypes::Boolean(b) => write!(f, "{}", b),
+            VariousVectorTypes::Text(t) => write!(f, "{}", t),
+        }
+    }
+}
+
+/// Splits a vector of [`VariousVectorTypes`] into one vector for each type, keeping the order in
+/// which the elements appear. Since the vector is taken by value, the strings are moved into their
+/// bucket instead of being cloned.
+pub fn into_buckets(v: Vec<VariousVectorTypes>) -> (Vec<i32>, Vec<f32>, Vec<bool>, Vec<String>) {
+    let mut integers: Vec<i32> = Vec::new();
+    let mut floats: Vec<f32> = Vec::new();
+    let mut booleans: Vec<bool> = Vec::new();
+    let mut texts: Vec<String> = Vec::new();
+
+    for item in v {
+        match item {
+            VariousVectorTypes::Integer(i) => integers.push(i),
+            VariousVectorTypes::Floating(f) => floats.push(f),
+            VariousVectorTypes::Boolean(b) => booleans.push(b),
+            VariousVectorTypes::Text(t) => texts.push(t),
+        }
+    }
+
+    (integers, floats, booleans, texts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_max_keeps_the_biggest_so_far() {
+        assert_eq!(running_max(&[3, 1, 4, 1, 5]), vec![3, 3, 4, 4, 5]);
+        assert_eq!(running_max(&[-2, -5]), vec![-2, -2]);
+    }
+
+    #[test]
+    fn running_max_of_nothing_is_empty() {
+        assert!(running_max(&[]).is_empty());
+    }
+
+    #[test]
+    fn longest_increasing_run_finds_the_longest() {
+        assert_eq!(longest_increasing_run(&[1, 2, 1, 2, 3, 4]), 4);
+        assert_eq!(longest_increasing_run(&[5, 4, 3]), 1);
+    }
+
+    #[test]
+    fn longest_increasing_run_edge_cases() {
+        assert_eq!(longest_increasing_run(&[7, 7, 7]), 1);
+        assert_eq!(longest_increasing_run(&[42]), 1);
+        assert_eq!(longest_increasing_run(&[]), 0);
+    }
+
+    #[test]
+    fn dot_product_of_equal_lengths() {
+        assert_eq!(dot_product(&[1, 2, 3], &[4, 5, 6]), Ok(32));
+        assert_eq!(dot_product(&[], &[]), Ok(0));
+    }
+
+    #[test]
+    fn dot_product_does_not_overflow_i32() {
+        assert_eq!(dot_product(&[i32::MAX, i32::MAX], &[2, 2]), Ok(4 * i32::MAX as i64));
+    }
+
+    #[test]
+    fn dot_product_rejects_different_lengths() {
+        assert_eq!(dot_product(&[1, 2, 3], &[7, 8]), Err(LengthMismatch { left: 3, right: 2 }));
+    }
+
+    #[test]
+    fn multiplication_table_of_three() {
+        assert_eq!(multiplication_table(3), vec![vec![1, 2, 3], vec![2, 4, 6], vec![3, 6, 9]]);
+    }
+
+    #[test]
+    fn multiplication_table_is_n_by_n() {
+        for n in 0..6 {
+            let table: Vec<Vec<u32>> = multiplication_table(n);
+
+            assert_eq!(table.len(), n as usize);
+            assert!(table.iter().all(|row| row.len() == n as usize));
+        }
+    }
+
+    #[test]
+    fn format_matrix_aligns_the_columns() {
+        assert_eq!(format_matrix(&multiplication_table(4)),
+                   " 1  2  3  4\n 2  4  6  8\n 3  6  9 12\n 4  8 12 16");
+        assert_eq!(format_matrix(&[]), "");
+    }
+
+    #[test]
+    fn into_buckets_of_the_chapter_example() {
+        let v: Vec<VariousVectorTypes> = vec![
+            VariousVectorTypes::Integer(4),
+            VariousVectorTypes::Floating(3.72),
+            VariousVectorTypes::Boolean(true),
+            VariousVectorTypes::Text(String::from("Hello there")),
+        ];
+
+        let (integers, floats, booleans, texts) = into_buckets(v);
+        assert_eq!(integers, vec![4]);
+        assert_eq!(floats, vec![3.72]);
+        assert_eq!(booleans, vec![true]);
+        assert_eq!(texts, vec![String::from("Hello there")]);
+    }
+
+    #[test]
+    fn into_buckets_keeps_the_order() {
+        let v: Vec<VariousVectorTypes> = vec![VariousVectorTypes::Integer(1),
+                                              VariousVectorTypes::Boolean(false),
+                                              VariousVectorTypes::Integer(2),
+                                              VariousVectorTypes::Boolean(true)];
+
+        let (integers, floats, booleans, texts) = into_buckets(v);
+        assert_eq!(integers, vec![1, 2]);
+        assert_eq!(booleans, vec![false, true]);
+        assert!(floats.is_empty() && texts.is_empty());
+    }
+
+    #[test]
+    fn from_picks_the_right_variant() {
+        assert!(matches!(VariousVectorTypes::from(4), VariousVectorTypes::Integer(4)));
+        assert!(matches!(VariousVectorTypes::from(3.7_f32),
+                         VariousVectorTypes::Floating(x) if x == 3.7));
+        assert!(matches!(VariousVectorTypes::from(true), VariousVectorTypes::Boolean(true)));
+        assert!(matches!(VariousVectorTypes::from(String::from("x")),
+                         VariousVectorTypes::Text(t) if t == "x"));
+    }
+
+    #[test]
+    fn into_works_when_pushing() {
+        let mut v: Vec<VariousVectorTypes> = vec![4.into()];
+        v.push(false.into());
+
+        assert!(matches!(v[..],
+                         [VariousVectorTypes::Integer(4), VariousVectorTypes::Boolean(false)]));
+    }
+
+    #[test]
+    fn display_shows_only_the_value() {
+        assert_eq!(VariousVectorTypes::Integer(-4).to_string(), "-4");
+        assert_eq!(VariousVectorTypes::Floating(3.72).to_string(), "3.72");
+        assert_eq!(VariousVectorTypes::Boolean(true).to_string(), "true");
+        assert_eq!(VariousVectorTypes::Text(String::from("Hello there")).to_string(),
+                   "Hello there");
+    }
+
+    #[test]
+    fn remove_value_present() {
+        let mut v: Vec<i32> = vec![1, 2, 3, 4];
+
+        assert!(remove_value(&mut v, &3));
+        assert_eq!(v, vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn remove_value_absent() {
+        let mut v: Vec<&str> = vec!["a", "b"];
+
+        assert!(!remove_value(&mut v, &"c"));
+        assert_eq!(v, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn remove_value_removes_only_the_first_duplicate() {
+        let mut v: Vec<i32> = vec![5, 2, 7, 2, 9];
+
+        assert!(remove_value(&mut v, &2));
+        assert_eq!(v, vec![5, 7, 2, 9]);
+    }
+
+    #[test]
+    fn insert_sorted_in_every_position() {
+        let mut v: Vec<i32> = vec![2, 4, 6];
+
+        insert_sorted(&mut v, 5);
+        assert_eq!(v, vec![2, 4, 5, 6]);
+        insert_sorted(&mut v, 1);
+        assert_eq!(v, vec![1, 2, 4, 5, 6]);
+        insert_sorted(&mut v, 9);
+        assert_eq!(v, vec![1, 2, 4, 5, 6, 9]);
+    }
+
+    #[test]
+    fn insert_sorted_into_an_empty_vector() {
+        let mut v: Vec<i32> = Vec::new();
+        insert_sorted(&mut v, 3);
+
+        assert_eq!(v, vec![3]);
+    }
+
+    #[test]
+    fn insert_sorted_keeps_duplicates_together() {
+        let mut v: Vec<i32> = vec![1, 3, 3, 5];
+        insert_sorted(&mut v, 3);
+
+        assert_eq!(v, vec![1, 3, 3, 3, 5]);
+    }
+
+    #[test]
+    fn observe_growth_never_shrinks() {
+        let capacities: Vec<usize> = observe_growth(100);
+
+        assert_eq!(capacities.len(), 100);
+        assert!(capacities.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(capacities.iter().enumerate().all(|(i, &capacity)| capacity > i));
+    }
+
+    #[test]
+    fn observe_growth_jumps_only_when_full() {
+        let capacities: Vec<usize> = observe_growth(100);
+
+        // Before the push at index `i` the vector holds `i` elements: it grows only if they fill it
+        for i in 1..capacities.len() {
+            let grew: bool = capacities[i] != capacities[i - 1];
+            assert_eq!(grew, capacities[i - 1] == i, "push number {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn observe_growth_of_nothing() {
+        assert!(observe_growth(0).is_empty());
+    }
+}
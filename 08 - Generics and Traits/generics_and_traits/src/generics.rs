@@ -0,0 +1,93 @@
+/// A generic function has one or more **type parameters**, written between `<>` after its name.
+/// Inside the function the type parameter is used like any other type:
+///
+/// ```rust
+/// fn first<T>(list: &[T]) -> &T {
+///     &list[0]
+/// }
+/// ```
+///
+/// The compiler writes a copy of the function for each type that we actually use it with (this is
+/// called **monomorphization**), so generics cost nothing when the program runs.
+pub fn generics() {
+    {
+        println!("{:?} | {:?}", swap(1, 2), swap("left", "right"));
+    }
+
+    /* Not every type can be compared: to use `<` and `>` on a `T`, we have to ask for it with a
+     * **trait bound**, `T: PartialOrd`. We also ask for `Copy`, so that we can return the values
+     * themselves instead of references to them.
+     */
+
+    {
+        let integers: [i32; 6] = [34, 50, 25, 100, 65, -3];
+        let floats: [f64; 4] = [0.5, -1.25, 8.0, 3.5];
+        let empty: [char; 0] = [];
+
+        println!("{:?} | {:?} | {:?} | {:?}", min_max(&integers), min_max(&floats),
+                 min_max(&['y']), min_max(&empty));
+    }
+}
+
+/// Swaps two values of the same type
+pub fn swap<T>(a: T, b: T) -> (T, T) {
+    (b, a)
+}
+
+/// Finds the smallest and the biggest element of `list` in one pass, or `None` if it's empty.
+///
+/// `PartialOrd` is enough for floats, but `NaN` is neither smaller nor bigger than anything: it's
+/// picked only if it's the first element, and after that it's never replaced.
+pub fn min_max<T: PartialOrd + Copy>(list: &[T]) -> Option<(T, T)> {
+    let (&first, rest) = list.split_first()?;
+    let (mut min, mut max): (T, T) = (first, first);
+
+    for &item in rest {
+        if item < min {
+            min = item;
+        }
+        if item > max {
+            max = item;
+        }
+    }
+
+    Some((min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swap_values_of_any_type() {
+        assert_eq!(swap(1, 2), (2, 1));
+        assert_eq!(swap("left", "right"), ("right", "left"));
+        assert_eq!(swap(String::from("a"), String::from("b")),
+                   (String::from("b"), String::from("a")));
+    }
+
+    #[test]
+    fn min_max_of_integers() {
+        assert_eq!(min_max(&[34, 50, 25, 100, 65, -3]), Some((-3, 100)));
+        assert_eq!(min_max(&['y']), Some(('y', 'y')));
+        assert_eq!(min_max::<i32>(&[]), None);
+    }
+
+    #[test]
+    fn min_max_of_floats() {
+        let (min, max): (f64, f64) = min_max(&[0.5, -1.25, 8.0, 3.5]).unwrap();
+
+        // `total_cmp` compares floats exactly, without the special cases of `==`
+        assert!(min.total_cmp(&-1.25).is_eq());
+        assert!(max.total_cmp(&8.0).is_eq());
+    }
+
+    #[test]
+    fn min_max_skips_nan_after_the_first_element() {
+        let (min, max): (f64, f64) = min_max(&[1.0, f64::NAN, -2.0]).unwrap();
+        assert!(min.total_cmp(&-2.0).is_eq() && max.total_cmp(&1.0).is_eq());
+
+        let (min, max): (f64, f64) = min_max(&[f64::NAN, 1.0]).unwrap();
+        assert!(min.is_nan() && max.is_nan());
+    }
+}
@@ -0,0 +1,10 @@
+pub mod generics;
+pub mod traits;
+
+/// **Generics** let us write a function or a struct once, and use it with many different types.
+/// **Traits** describe what a type can do, and they are used to tell which types a generic
+/// function accepts.
+fn main() {
+    generics::generics();
+    traits::traits();
+}
@@ -0,0 +1,96 @@
+/// A **trait** is a set of methods that a type promises to have. A struct can only hold values of
+/// one type, but with a **trait object**, `Box<dyn Trait>`, we can keep values of different types
+/// together, as long as they all implement the trait.
+///
+/// When a method is called on a trait object, the program looks up which implementation to run
+/// only while it's running: this is called **dynamic dispatch**. With generics, instead, the
+/// choice is made by the compiler.
+pub fn traits() {
+    {
+        let screen: Screen = Screen {
+            components: vec![
+                Box::new(Button { width: 50, height: 10, label: String::from("OK") }),
+                Box::new(Checkbox { label: String::from("Remember me"), checked: true }),
+                Box::new(Button { width: 80, height: 10, label: String::from("Cancel") }),
+            ],
+        };
+
+        for drawing in screen.run() {
+            println!("{}", drawing);
+        }
+    }
+}
+
+/// Anything that can be drawn on the screen. Here "drawing" means describing it with a string.
+pub trait Draw {
+    fn draw(&self) -> String;
+}
+
+pub struct Button {
+    pub width: u32,
+    pub height: u32,
+    pub label: String,
+}
+
+impl Draw for Button {
+    fn draw(&self) -> String {
+        format!("[ {} ] ({}x{})", self.label, self.width, self.height)
+    }
+}
+
+pub struct Checkbox {
+    pub label: String,
+    pub checked: bool,
+}
+
+impl Draw for Checkbox {
+    fn draw(&self) -> String {
+        let mark: char = if self.checked { 'x' } else { ' ' };
+        format!("[{}] {}", mark, self.label)
+    }
+}
+
+/// A screen with many components of different types: the only thing that the screen knows about
+/// them is that they implement [`Draw`].
+pub struct Screen {
+    pub components: Vec<Box<dyn Draw>>,
+}
+
+impl Screen {
+    /// Draws every component, in order
+    pub fn run(&self) -> Vec<String> {
+        self.components.iter().map(|component| component.draw()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_component_draws_itself() {
+        let button: Button = Button { width: 50, height: 10, label: String::from("OK") };
+        let unchecked: Checkbox = Checkbox { label: String::from("Subscribe"), checked: false };
+
+        assert_eq!(button.draw(), "[ OK ] (50x10)");
+        assert_eq!(unchecked.draw(), "[ ] Subscribe");
+    }
+
+    #[test]
+    fn screen_draws_mixed_components_in_order() {
+        let screen: Screen = Screen {
+            components: vec![
+                Box::new(Button { width: 50, height: 10, label: String::from("OK") }),
+                Box::new(Checkbox { label: String::from("Remember me"), checked: true }),
+                Box::new(Button { width: 80, height: 10, label: String::from("Cancel") }),
+            ],
+        };
+
+        assert_eq!(screen.run(), vec!["[ OK ] (50x10)", "[x] Remember me", "[ Cancel ] (80x10)"]);
+    }
+
+    #[test]
+    fn empty_screen_draws_nothing() {
+        assert!(Screen { components: Vec::new() }.run().is_empty());
+    }
+}